@@ -142,7 +142,7 @@ When(
 		err = await this.blastAsset(
 			caller,
 			JSON.stringify(assets[0].metadata),
-			assetId,
+			[assetId, assets[0].metadata.weight],
 			[
 				{
 					category: "Upstream",
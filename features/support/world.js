@@ -219,7 +219,8 @@ class UserStoryWorld {
 			let parentId = i + start;
 
 			if (parentId > 0) {
-				assetParent = parentId;
+				// use asset weight as the parent's quantity used by the child
+				assetParent = [parentId, asset.metadata.weight];
 				// pause the parent asset
 				await this.pauseAsset(caller, parentId);
 			}
@@ -40,6 +40,11 @@ Feature: User Story 1
           {
             "category": "Upstream",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 10,
             "date": 1682632800
@@ -49,7 +54,7 @@ Feature: User Story 1
       },
       "events": [
         {"event":{"name":"Blasted","args":["1","{\"weight\":100}","5CXgNxM5hQSk9hiKxmYsLPhGun363r4J3q98A6RtHfMZauR4",null]}},
-        {"event":{"name":"Emission","args":["1","Upstream","Some Algorithm", true,"1,682,632,800","10"]}}
+        {"event":{"name":"Emission","args":["1","Upstream","Some Algorithm", true,"1,682,632,800","10",null]}}
       ]
     }
     """
@@ -92,6 +97,11 @@ Feature: User Story 1
         {
           "category": "Upstream",
           "dataSource": "0x536f6d6520416c676f726974686d",
+          "uncertainty": 0,
+          "lotId": 0,
+          "evidenceHash": null,
+          "offsetValue": 0,
+          "methodology": 0,
           "balanced": true,
           "value": 10,
           "date": 1682632800
@@ -99,6 +109,11 @@ Feature: User Story 1
         {
           "category": "Transport",
           "dataSource": "0x536f6d6520416c676f726974686d",
+          "uncertainty": 0,
+          "lotId": 0,
+          "evidenceHash": null,
+          "offsetValue": 0,
+          "methodology": 0,
           "balanced": true,
           "value": 10,
           "date": 1702632800
@@ -106,7 +121,7 @@ Feature: User Story 1
       ],
       "events": [
         {"event":{"name":"Transfer","args":["5CXgNxM5hQSk9hiKxmYsLPhGun363r4J3q98A6RtHfMZauR4","5FTrX9Po5UMmwze8Um87zjmAazxYTrWUrt61ZkTKBQ5FHbMy","1"]}},
-        {"event":{"name":"Emission","args":["1","Transport","Some Algorithm", true,"1,702,632,800","10"]}}
+        {"event":{"name":"Emission","args":["1","Transport","Some Algorithm", true,"1,702,632,800","10",null]}}
       ]
     }
     """
@@ -147,6 +162,11 @@ Feature: User Story 1
         {
           "category": "Upstream",
           "dataSource": "0x536f6d6520416c676f726974686d",
+          "uncertainty": 0,
+          "lotId": 0,
+          "evidenceHash": null,
+          "offsetValue": 0,
+          "methodology": 0,
           "balanced": true,
           "value": 10,
           "date": 1682632800
@@ -154,13 +174,18 @@ Feature: User Story 1
         {
           "category": "Transport",
           "dataSource": "0x536f6d6520416c676f726974686d",
+          "uncertainty": 0,
+          "lotId": 0,
+          "evidenceHash": null,
+          "offsetValue": 0,
+          "methodology": 0,
           "balanced": true,
           "value": 10,
           "date": 1782632800
         }
       ],
       "events": [
-        {"event":{"name":"Emission","args":["1","Transport", "Some Algorithm", true,"1,782,632,800","10"]}}
+        {"event":{"name":"Emission","args":["1","Transport", "Some Algorithm", true,"1,782,632,800","10",null]}}
       ]
     }
     """
@@ -226,12 +251,17 @@ Feature: User Story 1
           {
             "category": "Upstream",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 5,
             "date": 1755040054
           }
         ],
-        "parent": 2
+        "parent": [2, 25]
       },
       {
         "assetId": 2,
@@ -240,12 +270,17 @@ Feature: User Story 1
           {
             "category": "Upstream",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 10,
             "date": 1705040054
           }
         ],
-        "parent": 1
+        "parent": [1, 50]
       },
       {
         "assetId": 1,
@@ -254,6 +289,11 @@ Feature: User Story 1
           {
             "category": "Upstream",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 15,
             "date": 1682632800
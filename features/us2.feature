@@ -82,12 +82,17 @@ Feature: User Story 2
           {
             "category": "Process",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 5,
             "date": 1765040054
           }
         ],
-        "parent": 3
+        "parent": [3, 15]
       },
       {
         "assetId": 3,
@@ -96,6 +101,11 @@ Feature: User Story 2
           {
             "category": "Upstream",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 5,
             "date": 1755040054
@@ -103,12 +113,17 @@ Feature: User Story 2
           {
             "category": "Process",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 5,
             "date": 1755040054
           }
         ],
-        "parent": 2
+        "parent": [2, 25]
       },
       {
         "assetId": 2,
@@ -117,12 +132,17 @@ Feature: User Story 2
           {
             "category": "Upstream",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 10,
             "date": 1705040054
           }
         ],
-        "parent": 1
+        "parent": [1, 50]
       },
       {
         "assetId": 1,
@@ -131,6 +151,11 @@ Feature: User Story 2
           {
             "category": "Upstream",
             "dataSource": "0x536f6d6520416c676f726974686d",
+            "uncertainty": 0,
+            "lotId": 0,
+            "evidenceHash": null,
+            "offsetValue": 0,
+            "methodology": 0,
             "balanced": true,
             "value": 15,
             "date": 1682632800
@@ -3,6 +3,7 @@
 #[ink::contract]
 mod asset_co2_emissions {
     use ink::prelude::collections::{BTreeMap, BTreeSet};
+    use ink::prelude::format;
 
     use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
@@ -16,6 +17,31 @@ mod asset_co2_emissions {
     // Max size of DataSource for CO2 Emission record.
     pub const MAX_DATA_SOURCE_LENGTH: u8 = 128;
 
+    // Max number of Asset ids returned when listing an account's Assets.
+    pub const MAX_LIST_RETURN: u16 = 256;
+
+    // Max number of Asset ids examined by a single scanning query.
+    pub const MAX_SCAN_LENGTH: u32 = 1000;
+
+    // Max size of an Asset's classification tags.
+    pub const MAX_TAGS_LENGTH: u8 = 128;
+
+    // Default weight of an Emissions category in basis points (1.0x).
+    pub const DEFAULT_CATEGORY_WEIGHT: u32 = 10_000;
+
+    // Tolerated clock skew (in seconds) for CO2 Emissions dated after the current block.
+    pub const FUTURE_DATE_TOLERANCE: u64 = 300;
+
+    // Max depth of an Asset in the provenance tree (number of its ancestors),
+    // bounding the cost of tree walks such as `query_emissions` within a single message.
+    pub const MAX_TREE_DEPTH: u32 = 128;
+
+    // Max number of child Assets split from a single parent.
+    pub const MAX_CHILDREN_PER_ASSET: u16 = 256;
+
+    // Max number of Assets blasted in a single batch.
+    pub const MAX_BLAST_BATCH: u8 = 100;
+
     /// Asset ID type.
     pub type AssetId = u128;
 
@@ -25,8 +51,27 @@ mod asset_co2_emissions {
     // CO2 Emissions Data Source represented by vector of bytes/characters.
     pub type DataSource = Vec<u8>;
 
-    // Optional argument for referencing a parent asset that is split into child assets.
-    pub type ParentDetails = Option<AssetId>;
+    // Quantity of the parent Asset used by a child Asset (information for external systems).
+    pub type ParentRelation = u128;
+
+    // Optional argument for referencing a parent asset that is split into child assets,
+    // together with the parent's quantity used by the child.
+    pub type ParentDetails = Option<(AssetId, ParentRelation)>;
+
+    // Transfer awaiting acknowledgment: the recipient and CO2 Emissions of the transfer.
+    pub type PendingTransfer = (AccountId, Vec<CO2Emissions>);
+
+    // Aggregated CO2 Emissions statistics.
+    #[derive(Debug, Default, PartialEq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct EmissionStats {
+        // Number of CO2 Emissions items.
+        count: u32,
+        // Total CO2 Emissions value in kg CO2.
+        total: u128,
+        // Offset part of the total: full value of balanced items, `offset_value` of the others.
+        balanced: u128,
+    }
 
     // The type returned when querying for an Asset.
     #[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
@@ -40,12 +85,15 @@ mod asset_co2_emissions {
 
     #[derive(Copy, Clone, Debug, PartialEq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    enum EmissionsCategory {
+    pub enum EmissionsCategory {
         Process,
         Transport,
         Upstream,
     }
 
+    // CO2 Emissions item, SCALE-encoded as a whole without versioning.
+    // Adding fields breaks decoding of records stored by earlier versions of the contract,
+    // which is why upgrading such deployments with `set_code` is not supported (see README).
     #[derive(Clone, Debug, PartialEq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct CO2Emissions {
@@ -57,8 +105,19 @@ mod asset_co2_emissions {
         balanced: bool,
         // CO2 Emissions in kg CO2 (to avoid fractions).
         value: u128,
+        // Absolute uncertainty (±) of the CO2 Emissions value in kg CO2.
+        uncertainty: u128,
         // Real CO2 Emissions date as UNIX timestamp, not block creation time.
         date: u64,
+        // External batch/lot identifier for reconciliation with e.g. ERP systems (0 if not used).
+        lot_id: u64,
+        // Optional hash (commitment) of an off-chain document supporting the record.
+        evidence_hash: Option<[u8; 32]>,
+        // Part of the CO2 Emissions value in kg CO2 that is offset (at most `value`);
+        // balanced items without it are stored fully offset.
+        offset_value: u128,
+        // Measurement methodology id (e.g. GHG Protocol, ISO 14064) from the registry (0 if unspecified).
+        methodology: u16,
     }
 
     /// The AssetCO2Emissions Error types.
@@ -93,6 +152,66 @@ mod asset_co2_emissions {
         DataSourceOverflow,
         // When an Asset with ID already exists.
         AssetAlreadyExists,
+        // When the same CO2 Emissions item is present more than once in a single call.
+        DuplicateEmissionInBatch,
+        // When the calling account is not the one an Asset has been offered to.
+        NotOffered,
+        // When an Asset has no pending offer.
+        OfferNotFound,
+        // When a parent <> child Asset relation is equal to 0.
+        InvalidAssetRelation,
+        // When the sum of child Asset relations exceeds the parent Asset's quantity.
+        ParentQuantityExceeded,
+        // When too many CO2 Emissions items have been added to an Asset in the current time window.
+        EmissionRateLimited,
+        // When an Asset is transferred to its current owner.
+        TransferToSelf,
+        // When the next Asset id is lower than an already assigned one (e.g. after a faulty upgrade).
+        IdRegression,
+        // When Data Sources are required and a CO2 Emissions item has an empty one.
+        DataSourceRequired,
+        // When a child Asset references itself as its parent.
+        SelfParent,
+        // When the value transferred with `blast` is lower than the blast fee.
+        InsufficientFee,
+        // When the contract balance is lower than the requested amount.
+        InsufficientBalance,
+        // When transferring funds out of the contract fails.
+        TransferFailed,
+        // When Asset tags exceed maximum length.
+        TagsOverflow,
+        // When minting of new Assets has been permanently disabled.
+        MintingSealed,
+        // When the offset part of a CO2 Emissions item exceeds its value.
+        OffsetExceedsValue,
+        // When an Asset has no CO2 Emissions item at the given index.
+        EmissionNotFound,
+        // When an Asset has been retired.
+        AlreadyRetired,
+        // When a batch of Assets to be blasted exceeds maximum length.
+        BatchOverflow,
+        // When the transferred value does not cover the storage deposit.
+        InsufficientDeposit,
+        // When the Asset has no pending transfer to the account.
+        TransferNotPending,
+        // When a total of CO2 Emissions exceeds maximum value.
+        EmissionsTotalOverflow,
+        // When CO2 Emission item is dated in the future.
+        FutureEmissionsDate,
+        // When transaction sender is not the proposed contract owner.
+        NotPendingOwner,
+        // When CO2 Emission item references a methodology that is not registered.
+        UnknownMethodology,
+        // When a parent Asset already has the maximum number of children.
+        ChildrenOverflow,
+        // When a child Asset would exceed the maximum provenance tree depth.
+        TreeDepthExceeded,
+        // When the Asset already has child Assets.
+        HasChildren,
+        // When the maximum number of snapshots has been taken.
+        SnapshotsOverflow,
+        // When a batch query asks for more than `MAX_LIST_RETURN` Assets.
+        BatchTooLarge,
     }
 
     /// This emits when an Asset gets created.
@@ -121,16 +240,118 @@ mod asset_co2_emissions {
         id: AssetId,
     }
 
+    /// This emits when an Asset gets unpaused.
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        id: AssetId,
+    }
+
+    /// This emits when an Asset gets retired at the end of its life.
+    #[ink(event)]
+    pub struct Retired {
+        #[ink(topic)]
+        id: AssetId,
+    }
+
     /// This emits when CO2 Emission is added.
     #[ink(event)]
     pub struct Emission {
         #[ink(topic)]
         id: AssetId,
+        #[ink(topic)]
         category: EmissionsCategory,
         data_source: DataSource,
         balanced: bool,
         date: u64,
         value: u128,
+        evidence_hash: Option<[u8; 32]>,
+    }
+
+    /// This emits when an Asset gets offered to a future owner.
+    #[ink(event)]
+    pub struct Offered {
+        #[ink(topic)]
+        id: AssetId,
+        to: AccountId,
+    }
+
+    /// This emits when an offered Asset gets claimed by its future owner.
+    #[ink(event)]
+    pub struct Claimed {
+        #[ink(topic)]
+        id: AssetId,
+        from: AccountId,
+        to: AccountId,
+    }
+
+    /// This emits when a pending offer for an Asset gets revoked.
+    #[ink(event)]
+    pub struct OfferRevoked {
+        #[ink(topic)]
+        id: AssetId,
+    }
+
+    /// This emits when a transfer awaiting the recipient's acknowledgment gets initiated.
+    #[ink(event)]
+    pub struct TransferInitiated {
+        #[ink(topic)]
+        id: AssetId,
+        from: AccountId,
+        to: AccountId,
+    }
+
+    /// This emits when the recipient acknowledges a pending transfer.
+    #[ink(event)]
+    pub struct TransferAcknowledged {
+        #[ink(topic)]
+        id: AssetId,
+        from: AccountId,
+        to: AccountId,
+    }
+
+    /// This emits when a CO2 Emissions item gets moved from one Asset to another.
+    #[ink(event)]
+    pub struct EmissionMoved {
+        #[ink(topic)]
+        from: AssetId,
+        #[ink(topic)]
+        to: AssetId,
+        // Index of the item in the source Asset's CO2 Emissions before the move.
+        index: u32,
+        emission: CO2Emissions,
+    }
+
+    /// This emits when an Asset's classification tags get set.
+    #[ink(event)]
+    pub struct AssetTagged {
+        #[ink(topic)]
+        id: AssetId,
+        tags: Vec<u8>,
+    }
+
+    /// This emits when a new contract owner gets proposed.
+    #[ink(event)]
+    pub struct OwnershipProposed {
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    /// This emits when the proposed contract owner accepts the ownership.
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    /// This emits when the contract owner withdraws funds from the contract.
+    #[ink(event)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
     }
 
     #[ink::trait_definition]
@@ -138,6 +359,7 @@ mod asset_co2_emissions {
         /// List all Assets assigned to an owner.
         ///
         /// Returns empty vector if an account does not own any Assets.
+        /// At most `MAX_LIST_RETURN` Assets with the lowest ids are returned.
         ///
         /// # Arguments
         ///
@@ -158,24 +380,41 @@ mod asset_co2_emissions {
         fn owner_of(&self, id: AssetId) -> Option<AccountId>;
 
         /// Blast an Asset.
+        /// The caller must pay the configured blast fee (zero by default), which is transferred to the contract owner.
         ///
         /// # Arguments
         ///
         /// * `to` - The account that will own the Asset.
         /// * `metadata` - Immutable Asset's metadata (physical details of steel); Can be a string, a JSON string or a link to IPFS.
         /// * `emissions` - CO2 Emissions during asset creation (like blasting or splitting).
-        /// * `parent` - Information about asset creation from the existing Asset (in the case of e.g. splitting) - identifier of the Asset's parent
+        /// * `parent` - Information about asset creation from the existing Asset (in the case of e.g. splitting):
+        ///                 - identifier of the Asset's parent
+        ///                 - information about relation (parent's quantity used) for external systems.
         ///
         /// # Errors
         ///
         /// * `AssetAlreadyExists` - When Asset already exists.
         /// * `AssetNotFound` - When the Asset's parent does not exist.
+        /// * `ChildrenOverflow` - When the Asset's parent already has `MAX_CHILDREN_PER_ASSET` children.
         /// * `DataSourceOverflow` - When Data Source for any of CO2 Emission items exceeds maximum length.
+        /// * `DataSourceRequired` - When Data Sources are required and any of CO2 Emission items has an empty one.
+        /// * `DuplicateEmissionInBatch` - When duplicates are rejected and list of CO2 Emissions contains the same item twice.
         /// * `EmissionsEmpty` - When list of CO2 Emissions is empty.
         /// * `EmissionsOverflow` - When list of CO2 Emissions exceeds maximum length.
+        /// * `FutureEmissionsDate` - When any of CO2 Emission items is dated after the current block.
+        /// * `IdRegression` - When the next Asset id is lower than an already assigned one.
+        /// * `InsufficientDeposit` - When the transferred value does not cover the blast fee and the storage deposit.
+        /// * `InsufficientFee` - When the transferred value is lower than the blast fee.
+        /// * `InvalidAssetRelation` - When the relation to the Asset's parent is equal to Zero.
         /// * `MetadataOverflow` - When Metadata exceeds maximum length.
+        /// * `MintingSealed` - When minting has been permanently disabled.
         /// * `NotPaused`- When Asset's parent is not paused.
         /// * `NotOwner` - When action executor is not a parent Asset owner.
+        /// * `ParentQuantityExceeded` - When the relation together with relations of the parent's other children exceeds the parent's quantity.
+        /// * `SelfParent` - When the parent is the Asset to be blasted.
+        /// * `TransferFailed` - When transferring the blast fee to the contract owner fails.
+        /// * `TreeDepthExceeded` - When the Asset would have more than `MAX_TREE_DEPTH` ancestors.
+        /// * `UnknownMethodology` - When any of CO2 Emission items references a methodology that is not registered.
         /// * `ZeroEmissionsItem` When emission value for any of CO2 Emission items is equal to Zero.
         ///
         /// # Events
@@ -183,7 +422,7 @@ mod asset_co2_emissions {
         /// * `Blasted` - When an Asset gets blasted.
         /// * `Emissions` - When CO2 Emissions are added. Emitted per each CO2 Emission item.
         ///
-        #[ink(message)]
+        #[ink(message, payable)]
         fn blast(
             &mut self,
             to: AccountId,
@@ -203,17 +442,24 @@ mod asset_co2_emissions {
         /// # Errors
         ///
         /// * `AlreadyPaused` - When the Asset is paused.
+        /// * `AlreadyRetired` - When the Asset is retired.
         /// * `AssetNotFound` - When the Asset does not exist.
         /// * `DataSourceOverflow` - When Data Source for any of CO2 Emission items exceeds maximum length.
+        /// * `DataSourceRequired` - When Data Sources are required and any of CO2 Emission items has an empty one.
+        /// * `DuplicateEmissionInBatch` - When duplicates are rejected and list of CO2 Emissions contains the same item twice.
         /// * `EmissionsEmpty` - When list of CO2 Emissions is empty.
         /// * `EmissionsOverflow` - When list of CO2 Emissions exceeds maximum length.
+        /// * `FutureEmissionsDate` - When any of CO2 Emission items is dated after the current block.
         /// * `NotOwner` - When transaction sender is not an owner.
+        /// * `TransferToSelf` - When the new owner is the current owner.
+        /// * `UnknownMethodology` - When any of CO2 Emission items references a methodology that is not registered.
         /// * `ZeroEmissionsItem` When emission value for any of CO2 Emission items is equal to Zero.
         ///
         /// # Events
         ///
         /// * `Transfer` - When Asset gets transferred.
         /// * `Emissions` - When CO2 Emissions are added. Emitted per each CO2 Emission item.
+        /// * `OfferRevoked` - When the Asset had a pending offer.
         ///
         #[ink(message)]
         fn transfer(
@@ -234,6 +480,7 @@ mod asset_co2_emissions {
         /// # Errors
         ///
         /// * `AlreadyPaused` - When the Asset is already paused.
+        /// * `AlreadyRetired` - When the Asset is retired.
         /// * `AssetNotFound` - When the Asset does not exist.
         /// * `NotOwner` - When transaction sender is not an owner.
         ///
@@ -243,6 +490,67 @@ mod asset_co2_emissions {
         #[ink(message)]
         fn pause(&mut self, id: AssetId) -> Result<(), AssetCO2EmissionsError>;
 
+        /// Revert stopped state for an Asset (e.g. paused by mistake).
+        /// The Asset can be transferred and get CO2 Emissions again.
+        /// An Asset already split into children stays paused, as they inherit its CO2 Emissions.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset to unlock.
+        ///
+        /// # Errors
+        ///
+        /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `HasChildren` - When the Asset has been split into child Assets.
+        /// * `NotOwner` - When transaction sender is not an owner.
+        /// * `NotPaused` - When the Asset is not paused.
+        ///
+        /// # Events
+        ///
+        /// * `Unpaused` - When asset gets unpaused.
+        #[ink(message)]
+        fn unpause(&mut self, id: AssetId) -> Result<(), AssetCO2EmissionsError>;
+
+        /// Retire an Asset at the end of its life (e.g. when steel gets consumed).
+        /// The Asset is no longer owned by anyone and cannot be transferred, paused or get new CO2 Emissions,
+        /// but its full history stays available via `get_asset` and `query_emissions`.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset to be retired.
+        /// * `emissions` - Final CO2 Emissions of the Asset, empty for a paused (split) Asset.
+        ///
+        /// # Errors
+        ///
+        /// * `AlreadyPaused` - When the Asset is paused (split) and final CO2 Emissions are given.
+        /// * `AlreadyRetired` - When the Asset is already retired.
+        /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `DataSourceOverflow` - When Data Source for any of CO2 Emission items exceeds maximum length.
+        /// * `DataSourceRequired` - When Data Sources are required and any of CO2 Emission items has an empty one.
+        /// * `DuplicateEmissionInBatch` - When duplicates are rejected and list of CO2 Emissions contains the same item twice.
+        /// * `EmissionsEmpty` - When list of CO2 Emissions is empty.
+        /// * `EmissionsOverflow` - When list of CO2 Emissions exceeds maximum length.
+        /// * `FutureEmissionsDate` - When any of CO2 Emission items is dated after the current block.
+        /// * `NotOwner` - When transaction sender is not an owner.
+        /// * `TransferFailed` - When refunding the storage deposit to the owner fails.
+        /// * `UnknownMethodology` - When any of CO2 Emission items references a methodology that is not registered.
+        /// * `ZeroEmissionsItem` When emission value for any of CO2 Emission items is equal to Zero.
+        ///
+        /// The storage deposit paid when the Asset was blasted is refunded to the owner.
+        ///
+        /// # Events
+        ///
+        /// * `Emissions` - When CO2 Emissions are added. Emitted per each CO2 Emission item.
+        /// * `OfferRevoked` - When the Asset had a pending offer.
+        /// * `Retired` - When asset gets retired.
+        ///
+        #[ink(message)]
+        fn retire(
+            &mut self,
+            id: AssetId,
+            emissions: Vec<CO2Emissions>,
+        ) -> Result<(), AssetCO2EmissionsError>;
+
         /// Query if an Asset is paused.
         ///
         /// Returns None if Asset does not exist.
@@ -265,9 +573,14 @@ mod asset_co2_emissions {
         /// # Errors
         ///
         /// * `AlreadyPaused` - When asset is paused.
+        /// * `AlreadyRetired` - When the Asset is retired.
         /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `DataSourceRequired` - When Data Sources are required and the CO2 Emission item has an empty one.
         /// * `EmissionsOverflow` - When list of CO2 Emissions exceeds maximum length.
+        /// * `EmissionRateLimited` - When the Asset's quota of CO2 Emissions for the current time window is used up.
+        /// * `FutureEmissionsDate` - When any of CO2 Emission items is dated after the current block.
         /// * `NotOwner` - When transaction sender is not an owner.
+        /// * `UnknownMethodology` - When any of CO2 Emission items references a methodology that is not registered.
         /// * `ZeroEmissionsItem` When emission value for any of CO2 Emission items is equal to Zero.
         ///
         /// # Events
@@ -357,6 +670,72 @@ mod asset_co2_emissions {
         paused: Mapping<AssetId, bool>,
         // Child Asset's parent.
         parent: Mapping<AssetId, ParentDetails>,
+        // Whether duplicated CO2 Emissions items within a single call are rejected.
+        reject_duplicate_emissions: bool,
+        // Number of times an Asset has been transferred.
+        transfer_count: Mapping<AssetId, u32>,
+        // Account an Asset has been offered to.
+        offers: Mapping<AssetId, AccountId>,
+        // Declared quantity of an Asset; a child Asset's quantity is its relation to the parent.
+        quantity: Mapping<AssetId, ParentRelation>,
+        // Sum of relations of all child Assets blasted from an Asset.
+        split_quantity: Mapping<AssetId, ParentRelation>,
+        // Maximum number of CO2 Emissions items added to an Asset per time window (0 means no limit).
+        max_emissions_per_window: u32,
+        // Length of the CO2 Emissions rate limit window in seconds.
+        window_seconds: u64,
+        // Start of the current rate limit window (in seconds) and number of CO2 Emissions items added in it.
+        emission_window: Mapping<AssetId, (u64, u32)>,
+        // Total of own CO2 Emissions of all Assets currently owned by an account.
+        owner_emissions: Mapping<AccountId, u128>,
+        // Weight of an Emissions category in basis points used by the weighted footprint.
+        category_weights: Mapping<EmissionsCategory, u32>,
+        // Highest value `next_id` has ever reached; ids below it must never be assigned again.
+        id_high_water: AssetId,
+        // Range (start index, number of items) of an Asset's CO2 Emissions added by its last transfer.
+        last_transfer_emissions: Mapping<AssetId, (u32, u32)>,
+        // If CO2 Emissions items must have a non-empty Data Source.
+        require_data_source: bool,
+        // Fee paid to the contract owner for blasting an Asset.
+        blast_fee: Balance,
+        // Number of CO2 Emissions items an Asset has been blasted with.
+        creation_emissions_count: Mapping<AssetId, u32>,
+        // Classification tags of an Asset (e.g. product type), independent of CO2 Emissions.
+        asset_tags: Mapping<AssetId, Vec<u8>>,
+        // If minting of new Assets has been permanently disabled.
+        minting_sealed: bool,
+        // Total offset (balanced) CO2 Emissions value ever recorded in the contract.
+        total_balanced_emissions: u128,
+        // What Assets are retired (end-of-life); their history is kept.
+        retired: Mapping<AssetId, bool>,
+        // Total CO2 Emissions value ever recorded across all Assets.
+        total_recorded_emissions: u128,
+        // Period-end figures by index: (block timestamp in seconds, total recorded CO2 Emissions).
+        snapshots: Mapping<u32, (u64, u128)>,
+        // Number of taken snapshots.
+        snapshot_count: u32,
+        // Deposit required with each blasted Asset, refunded on retirement.
+        storage_deposit: Balance,
+        // Deposits paid for Assets, still held by the contract.
+        deposits: Mapping<AssetId, Balance>,
+        // Total of the deposits held by the contract.
+        held_deposits: Balance,
+        // Number of Assets ever blasted.
+        total_assets: AssetId,
+        // Number of Assets not retired.
+        active_assets: u128,
+        // Transfers awaiting the recipient's acknowledgment: recipient and CO2 Emissions of the transfer.
+        pending_transfers: Mapping<AssetId, PendingTransfer>,
+        // Proposed contract owner, waiting to accept the ownership.
+        pending_owner: Option<AccountId>,
+        // Registered measurement methodology ids.
+        methodologies: Mapping<u16, bool>,
+        // Child Assets of each parent Asset, in blasting order.
+        children: Mapping<AssetId, Vec<AssetId>>,
+        // If Assets get paused for review when they reach `MAX_EMISSIONS_PER_ASSET`.
+        auto_pause_on_cap: bool,
+        // Depth of child Assets in the provenance tree (number of ancestors); root Assets are not stored.
+        depth: Mapping<AssetId, u32>,
     }
 
     impl Default for InfinityAsset {
@@ -378,6 +757,39 @@ mod asset_co2_emissions {
                 metadata: Mapping::new(),
                 paused: Mapping::new(),
                 parent: Mapping::new(),
+                reject_duplicate_emissions: false,
+                transfer_count: Mapping::new(),
+                offers: Mapping::new(),
+                quantity: Mapping::new(),
+                split_quantity: Mapping::new(),
+                max_emissions_per_window: 0,
+                window_seconds: 0,
+                emission_window: Mapping::new(),
+                owner_emissions: Mapping::new(),
+                category_weights: Mapping::new(),
+                id_high_water: 1,
+                last_transfer_emissions: Mapping::new(),
+                require_data_source: false,
+                blast_fee: 0,
+                creation_emissions_count: Mapping::new(),
+                asset_tags: Mapping::new(),
+                minting_sealed: false,
+                total_balanced_emissions: 0,
+                retired: Mapping::new(),
+                total_recorded_emissions: 0,
+                snapshots: Mapping::new(),
+                snapshot_count: 0,
+                storage_deposit: 0,
+                deposits: Mapping::new(),
+                held_deposits: 0,
+                total_assets: 0,
+                active_assets: 0,
+                pending_transfers: Mapping::new(),
+                pending_owner: None,
+                methodologies: Mapping::new(),
+                children: Mapping::new(),
+                auto_pause_on_cap: false,
+                depth: Mapping::new(),
             }
         }
 
@@ -400,11 +812,89 @@ mod asset_co2_emissions {
             // Only the owner of the contract may set the new owner.
             self.ensure_contract_owner(self.env().caller())?;
             self.contract_owner = new_owner;
+            self.pending_owner = None;
+            Ok(())
+        }
+
+        /// Proposes a new smart contract owner, who becomes the owner after accepting with `accept_contract_ownership`.
+        /// Unlike `set_contract_owner`, a mistyped account cannot take over privileged operations.
+        /// A new proposal replaces the previous one.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `new_owner` - The proposed owner of the smart contract.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        /// # Events
+        ///
+        /// * `OwnershipProposed` - When the new owner gets proposed.
+        ///
+        #[ink(message)]
+        pub fn propose_contract_owner(
+            &mut self,
+            new_owner: AccountId,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.pending_owner = Some(new_owner);
+            self.env().emit_event(OwnershipProposed { new_owner });
+            Ok(())
+        }
+
+        /// Accepts the smart contract ownership proposed to the caller.
+        ///
+        /// # Errors
+        ///
+        /// * `NotPendingOwner` - When transaction sender is not the proposed owner.
+        ///
+        /// # Events
+        ///
+        /// * `OwnershipTransferred` - When the ownership gets transferred.
+        ///
+        #[ink(message)]
+        pub fn accept_contract_ownership(&mut self) -> Result<(), AssetCO2EmissionsError> {
+            let caller = self.env().caller();
+            if self.pending_owner != Some(caller) {
+                return Err(AssetCO2EmissionsError::NotPendingOwner);
+            }
+
+            let previous_owner = self.contract_owner;
+            self.contract_owner = caller;
+            self.pending_owner = None;
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: caller,
+            });
+            Ok(())
+        }
+
+        /// Sets whether duplicated CO2 Emissions items within a single call are rejected.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `enabled` - If true, `blast` and `transfer` reject exact-duplicate CO2 Emissions items.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn set_reject_duplicate_emissions(
+            &mut self,
+            enabled: bool,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.reject_duplicate_emissions = enabled;
             Ok(())
         }
 
         /// Modifies the code which is used to execute calls to this contract address (`AccountId`).
         /// Must be called by current contract owner.
+        /// The new code must keep the storage layout, stored data is not migrated.
         ///
         /// # Arguments
         ///
@@ -421,1693 +911,7937 @@ mod asset_co2_emissions {
             ink::env::debug_println!("Switched code hash to {:?}.", code_hash);
         }
 
-        /// Insert new Asset in the Assets of `owner`.
-        fn insert_owned_asset(
-            &mut self,
-            owner: &AccountId,
-            asset_id: &AssetId,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            match self.owned_assets.get_mut(owner) {
-                None => {
-                    let mut new_owned_assets = BTreeSet::new();
-                    new_owned_assets.insert(*asset_id);
-                    self.owned_assets.insert(*owner, new_owned_assets);
-                }
-                Some(owned_assets) => {
-                    owned_assets.insert(*asset_id);
-                }
-            }
-            Ok(())
-        }
+        /// Compute the trend of an Asset's own CO2 Emissions over time.
+        ///
+        /// The Asset's records are sorted by `date` and split into an earlier and a later half;
+        /// for an odd number of records the middle one belongs to neither half.
+        /// The trend is the sum of values in the later half minus the sum of values in the earlier half,
+        /// so a positive result means emissions are accelerating and a negative one means they are slowing down.
+        /// Values are converted to `i128` and summed with saturating arithmetic.
+        /// An Asset with a single record has a flat trend (0).
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn emission_trend(&self, id: AssetId) -> Option<i128> {
+            let mut emissions = self.get_asset_emissions(id)?;
+            emissions.sort_by_key(|item| item.date);
+
+            let half = emissions.len() / 2;
+            let sum = |items: &[CO2Emissions]| {
+                items.iter().fold(0i128, |total, item| {
+                    total.saturating_add(i128::try_from(item.value).unwrap_or(i128::MAX))
+                })
+            };
+            let earlier = sum(&emissions[..half]);
+            let later = sum(&emissions[emissions.len() - half..]);
 
-        /// Remove Asset from the Assets of `owner`.
-        fn remove_owned_asset(
-            &mut self,
-            owner: &AccountId,
-            asset_id: &AssetId,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            self.owned_assets
-                .get_mut(owner)
-                .expect("Owned assets must exist when removing during Asset transfer")
-                .remove(asset_id);
-            Ok(())
+            Some(later.saturating_sub(earlier))
         }
 
-        /// Ensure that Asset does not exist.
-        fn ensure_not_exist(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
-            match self.asset_owner.contains(id) {
-                false => Ok(()),
-                true => Err(AssetCO2EmissionsError::AssetAlreadyExists),
-            }
+        /// List Assets whose metadata starts with the given prefix.
+        ///
+        /// The query scans sequentially assigned Asset ids, so it is paginated:
+        /// the first `skip` ids are skipped and at most `limit` ids are examined.
+        /// `limit` is capped at `MAX_SCAN_LENGTH`.
+        ///
+        /// # Arguments
+        ///
+        /// * `prefix` - Leading bytes of the Asset's metadata.
+        /// * `skip` - Number of Asset ids to skip.
+        /// * `limit` - Number of Asset ids to examine.
+        ///
+        #[ink(message)]
+        pub fn assets_with_metadata_prefix(
+            &self,
+            prefix: Vec<u8>,
+            skip: u32,
+            limit: u32,
+        ) -> Vec<AssetId> {
+            self.scan_ids(skip.into(), limit)
+                .filter(|id| {
+                    self.metadata
+                        .get(id)
+                        .is_some_and(|metadata| metadata.starts_with(&prefix))
+                })
+                .collect()
         }
 
-        /// Ensure that Asset does exist.
-        fn ensure_exists(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
-            match self.asset_owner.contains(id) {
-                true => Ok(()),
-                false => Err(AssetCO2EmissionsError::AssetNotFound),
-            }
-        }
+        /// Find Assets whose ownership indexes disagree.
+        /// Must be called by current contract owner.
+        ///
+        /// An Asset is reported when its owner is not tracking it in the owned Assets,
+        /// or when it has no owner but is still listed in any account's owned Assets.
+        /// The scan skips the first `skip` Asset ids and examines at most `limit` ids (capped at `MAX_SCAN_LENGTH`).
+        ///
+        /// # Arguments
+        ///
+        /// * `skip` - Number of Asset ids to skip.
+        /// * `limit` - Number of Asset ids to examine.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn audit_ownership_consistency(
+            &self,
+            skip: AssetId,
+            limit: u32,
+        ) -> Result<Vec<AssetId>, AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
 
-        /// Ensure the calling origin is the contract owner.
-        fn ensure_contract_owner(&self, caller: AccountId) -> Result<(), AssetCO2EmissionsError> {
-            match caller.eq(&self.contract_owner) {
-                true => Ok(()),
-                false => Err(AssetCO2EmissionsError::NotContractOwner),
-            }
+            Ok(self
+                .scan_ids(skip, limit)
+                .filter(|id| match self.asset_owner.get(id) {
+                    Some(owner) => !self
+                        .owned_assets
+                        .get(&owner)
+                        .is_some_and(|owned_assets| owned_assets.contains(id)),
+                    None => self
+                        .owned_assets
+                        .values()
+                        .any(|owned_assets| owned_assets.contains(id)),
+                })
+                .collect())
         }
 
-        /// Ensure the calling origin is the Asset owner.
-        fn ensure_owner(
-            &self,
-            id: &AssetId,
-            account: &AccountId,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            match self.asset_owner.get(id) {
-                None => Err(AssetCO2EmissionsError::AssetNotFound),
-                Some(owner) => {
-                    if owner.eq(account) {
-                        Ok(())
-                    } else {
-                        Err(AssetCO2EmissionsError::NotOwner)
-                    }
-                }
-            }
+        /// Get the number of times an Asset has been transferred.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn transfer_count_of(&self, id: AssetId) -> Option<u32> {
+            self.ensure_recorded(&id).ok()?;
+            Some(self.transfer_count.get(id).unwrap_or(0))
         }
 
-        /// Ensure the Asset is `Paused`.
-        fn ensure_paused(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
-            match self.has_paused(*id) {
-                None => Err(AssetCO2EmissionsError::AssetNotFound),
-                Some(false) => Err(AssetCO2EmissionsError::NotPaused),
-                Some(true) => Ok(()),
-            }
-        }
+        /// Offer an Asset to a future owner without transferring it yet.
+        /// The offered account completes the transfer with `claim`.
+        /// A new offer replaces the previous one.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset to be offered.
+        /// * `to` - The account allowed to claim the Asset.
+        ///
+        /// # Errors
+        ///
+        /// * `AlreadyPaused` - When the Asset is paused.
+        /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `NotOwner` - When transaction sender is not an owner.
+        ///
+        /// # Events
+        ///
+        /// * `Offered` - When Asset gets offered.
+        ///
+        #[ink(message)]
+        pub fn offer(&mut self, id: AssetId, to: AccountId) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_owner(&id, &self.env().caller())?;
+            self.ensure_not_paused(&id)?;
 
-        /// Ensure the Asset is not `Paused`.
-        fn ensure_not_paused(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
-            match self.has_paused(*id) {
-                None => Err(AssetCO2EmissionsError::AssetNotFound),
-                Some(true) => Err(AssetCO2EmissionsError::AlreadyPaused),
-                Some(false) => Ok(()),
-            }
-        }
+            self.offers.insert(id, &to);
+            self.env().emit_event(Offered { id, to });
 
-        /// Ensure the parent details of child Asset are correct.
-        fn ensure_proper_parent(
-            &self,
-            parent: &ParentDetails,
-            caller: &AccountId,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            match parent {
-                None => Ok(()),
-                Some(parent_id) => {
-                    self.ensure_owner(parent_id, caller)?;
-                    self.ensure_paused(parent_id)
-                }
-            }
+            Ok(())
         }
 
-        /// Ensure that CO2 Emissions are correct: not empty, not unbounded, and all items are correct.
-        fn ensure_emissions_correct(
-            &self,
-            asset: Option<AssetId>,
-            emissions: &Vec<CO2Emissions>,
+        /// Claim an Asset offered to the calling account, transferring it from the current owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset to be claimed.
+        /// * `emissions` - CO2 Emissions caused by the Asset transfer.
+        ///
+        /// # Errors
+        ///
+        /// * `AlreadyPaused` - When the Asset is paused.
+        /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `DataSourceOverflow` - When Data Source for any of CO2 Emission items exceeds maximum length.
+        /// * `DataSourceRequired` - When Data Sources are required and any of CO2 Emission items has an empty one.
+        /// * `EmissionsEmpty` - When list of CO2 Emissions is empty.
+        /// * `EmissionsOverflow` - When list of CO2 Emissions exceeds maximum length.
+        /// * `FutureEmissionsDate` - When any of CO2 Emission items is dated after the current block.
+        /// * `NotOffered` - When the Asset has not been offered to transaction sender.
+        /// * `UnknownMethodology` - When any of CO2 Emission items references a methodology that is not registered.
+        /// * `ZeroEmissionsItem` When emission value for any of CO2 Emission items is equal to Zero.
+        ///
+        /// # Events
+        ///
+        /// * `Transfer` - When Asset gets transferred.
+        /// * `Emissions` - When CO2 Emissions are added. Emitted per each CO2 Emission item.
+        /// * `Claimed` - When Asset gets claimed.
+        ///
+        #[ink(message)]
+        pub fn claim(
+            &mut self,
+            id: AssetId,
+            emissions: Vec<CO2Emissions>,
         ) -> Result<(), AssetCO2EmissionsError> {
-            self.ensure_emissions_not_empty(emissions)?;
-            self.ensure_emissions_not_unbounded(emissions)?;
-
-            match asset {
-                None => (),
-                Some(asset_id) => {
-                    let mut updated_emissions =
-                        self.co2_emissions.get(asset_id).unwrap_or(Vec::new());
-                    updated_emissions.extend_from_slice(emissions);
-                    self.ensure_emissions_not_unbounded(&updated_emissions)?;
-                }
+            let to = self.env().caller();
+            let from = self
+                .asset_owner
+                .get(id)
+                .ok_or_else(|| self.missing_asset_error(&id))?;
+
+            match self.offers.get(id) {
+                Some(offered) if offered.eq(&to) => (),
+                _ => return Err(AssetCO2EmissionsError::NotOffered),
             }
 
-            // Ensure all CO2 Emissions items are correct.
-            emissions.iter().try_for_each(|item| {
-                self.ensure_emissions_item_correct(item)?;
-                Ok(())
-            })
-        }
+            self.transfer_asset(from, to, id, emissions)?;
+            self.env().emit_event(Claimed { id, from, to });
 
-        /// Ensure CO2 Emissions vec is not empty.
-        fn ensure_emissions_not_empty(
-            &self,
-            emissions: &Vec<CO2Emissions>,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            match emissions.len() {
-                0 => Err(AssetCO2EmissionsError::EmissionsEmpty),
-                _ => Ok(()),
-            }
+            Ok(())
         }
 
-        /// Ensure length of CO2 Emissions vec is not greater than `MAX_EMISSIONS_PER_ASSET`.
-        fn ensure_emissions_not_unbounded(
-            &self,
-            emissions: &Vec<CO2Emissions>,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            if emissions.len() > MAX_EMISSIONS_PER_ASSET as usize {
-                return Err(AssetCO2EmissionsError::EmissionsOverflow);
+        /// Revoke a pending offer for an Asset.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        /// # Errors
+        ///
+        /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `NotOwner` - When transaction sender is not an owner.
+        /// * `OfferNotFound` - When the Asset has no pending offer.
+        ///
+        /// # Events
+        ///
+        /// * `OfferRevoked` - When the offer gets revoked.
+        ///
+        #[ink(message)]
+        pub fn revoke_offer(&mut self, id: AssetId) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_owner(&id, &self.env().caller())?;
+            if !self.offers.contains(id) {
+                return Err(AssetCO2EmissionsError::OfferNotFound);
             }
+
+            self.offers.remove(id);
+            self.env().emit_event(OfferRevoked { id });
+
             Ok(())
         }
 
-        /// Ensure length of Data Source for CO2 Emission item is not greater than `MAX_DATA_SOURCE_LENGTH`.
-        fn ensure_emissions_data_src_not_unbounded(
-            &self,
-            data_source: &DataSource,
+        /// Initiate a transfer of an Asset that completes once the recipient acknowledges receipt
+        /// with `acknowledge_transfer`. Until then the Asset stays with the sender.
+        /// A new transfer replaces the previous pending one.
+        ///
+        /// # Arguments
+        ///
+        /// * `to` - The Asset recipient.
+        /// * `id` - The Asset to be transferred.
+        /// * `emissions` - CO2 Emissions caused by the Asset transfer, recorded on acknowledgment.
+        ///
+        /// # Errors
+        ///
+        /// * `AlreadyPaused` - When the Asset is paused.
+        /// * `AlreadyRetired` - When the Asset is retired.
+        /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `DataSourceOverflow` - When Data Source for any of CO2 Emission items exceeds maximum length.
+        /// * `DataSourceRequired` - When Data Sources are required and any of CO2 Emission items has an empty one.
+        /// * `DuplicateEmissionInBatch` - When duplicates are rejected and list of CO2 Emissions contains the same item twice.
+        /// * `EmissionsEmpty` - When list of CO2 Emissions is empty.
+        /// * `EmissionsOverflow` - When list of CO2 Emissions exceeds maximum length.
+        /// * `FutureEmissionsDate` - When any of CO2 Emission items is dated after the current block.
+        /// * `NotOwner` - When transaction sender is not an owner.
+        /// * `TransferToSelf` - When the recipient is the current owner.
+        /// * `UnknownMethodology` - When any of CO2 Emission items references a methodology that is not registered.
+        /// * `ZeroEmissionsItem` When emission value for any of CO2 Emission items is equal to Zero.
+        ///
+        /// # Events
+        ///
+        /// * `TransferInitiated` - When the transfer gets initiated.
+        ///
+        #[ink(message)]
+        pub fn initiate_transfer(
+            &mut self,
+            to: AccountId,
+            id: AssetId,
+            emissions: Vec<CO2Emissions>,
         ) -> Result<(), AssetCO2EmissionsError> {
-            if data_source.len() > MAX_DATA_SOURCE_LENGTH as usize {
-                return Err(AssetCO2EmissionsError::DataSourceOverflow);
+            let from = self.env().caller();
+            self.ensure_not_retired(&id)?;
+            self.ensure_owner(&id, &from)?;
+            if from == to {
+                return Err(AssetCO2EmissionsError::TransferToSelf);
             }
+            self.ensure_not_paused(&id)?;
+            self.ensure_emissions_correct(Some(id), &emissions)?;
+
+            self.pending_transfers.insert(id, &(to, emissions));
+            self.env().emit_event(TransferInitiated { id, from, to });
+
             Ok(())
         }
 
-        /// Ensure CO2 Emissions item is correct.
-        fn ensure_emissions_item_correct(
-            &self,
-            item: &CO2Emissions,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            self.ensure_emissions_data_src_not_unbounded(&item.data_source)?;
-            self.ensure_emissions_item_not_zero(item)?;
+        /// Acknowledge receipt of an Asset, completing the transfer initiated to the calling account.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        /// # Errors
+        ///
+        /// * `TransferNotPending` - When the Asset has no pending transfer to transaction sender.
+        /// * All errors returned by `transfer` when the Asset cannot be transferred anymore.
+        ///
+        /// # Events
+        ///
+        /// * `Transfer` - When Asset gets transferred.
+        /// * `Emissions` - When CO2 Emissions are added. Emitted per each CO2 Emission item.
+        /// * `OfferRevoked` - When the Asset had a pending offer to another account.
+        /// * `TransferAcknowledged` - When the transfer gets acknowledged.
+        ///
+        #[ink(message)]
+        pub fn acknowledge_transfer(&mut self, id: AssetId) -> Result<(), AssetCO2EmissionsError> {
+            let to = self.env().caller();
+            let emissions = match self.pending_transfers.get(id) {
+                Some((recipient, emissions)) if recipient.eq(&to) => emissions,
+                _ => return Err(AssetCO2EmissionsError::TransferNotPending),
+            };
+            let from = self
+                .asset_owner
+                .get(id)
+                .ok_or_else(|| self.missing_asset_error(&id))?;
+
+            self.transfer_asset(from, to, id, emissions)?;
+            self.env().emit_event(TransferAcknowledged { id, from, to });
+
             Ok(())
         }
 
-        /// Ensure CO2 Emissions item value is non-zero.
-        fn ensure_emissions_item_not_zero(
-            &self,
-            emissions: &CO2Emissions,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            match emissions.value {
-                0 => Err(AssetCO2EmissionsError::ZeroEmissionsItem),
-                _ => Ok(()),
+        /// Get an Asset's own CO2 Emissions totals per calendar year.
+        ///
+        /// The year is derived from each item's `date` (UNIX timestamp, UTC) with an exact
+        /// Gregorian calendar conversion, so leap years are taken into account.
+        /// Totals saturate at `u128::MAX` and are sorted by year ascending.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn emissions_by_year(&self, id: AssetId) -> Option<Vec<(u32, u128)>> {
+            let mut totals: BTreeMap<u32, u128> = BTreeMap::new();
+            for item in self.get_asset_emissions(id)? {
+                let total = totals.entry(Self::year_of(item.date)).or_insert(0);
+                *total = total.saturating_add(item.value);
             }
+            Some(totals.into_iter().collect())
         }
 
-        /// Ensure metadata does not exceed `MAX_METADATA_LENGTH`.
-        fn ensure_proper_metadata(
-            &self,
-            metadata: &Metadata,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            if metadata.len() > MAX_METADATA_LENGTH as usize {
-                return Err(AssetCO2EmissionsError::MetadataOverflow);
-            }
-            Ok(())
+        /// Get the number of Assets in an Asset's tree, i.e. the Asset itself and all its ancestors.
+        /// This is the number of items `query_emissions` would return,
+        /// computed from parent links only without assembling Asset details.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn lineage_size(&self, id: AssetId) -> Option<u32> {
+            self.ensure_recorded(&id).ok()?;
+            Some(self.lineage(id).len() as u32)
         }
 
-        /// Save new CO2 Emissions for Asset and emit an event for each emission item.
-        fn save_new_co2_emissions(&mut self, id: &AssetId, emissions: &[CO2Emissions]) {
-            let mut updated_emissions = self.co2_emissions.get(id).unwrap_or(Vec::new());
-            updated_emissions.extend_from_slice(emissions);
-
-            self.co2_emissions.insert(id, &updated_emissions);
+        /// Get the total CO2 Emissions and their total uncertainty across an Asset's tree.
+        /// Both values are summed over the Asset and all its ancestors, saturating at `u128::MAX`.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn total_with_uncertainty(&self, id: AssetId) -> Option<(u128, u128)> {
+            self.ensure_recorded(&id).ok()?;
+            Some(
+                self.lineage_emissions(id)
+                    .iter()
+                    .fold((0, 0), |(value, uncertainty), item| {
+                        (
+                            value.saturating_add(item.value),
+                            uncertainty.saturating_add(item.uncertainty),
+                        )
+                    }),
+            )
+        }
 
-            // emit an event for each emission item.
-            emissions.iter().for_each(|emission| {
-                self.env().emit_event(Emission {
-                    id: *id,
-                    category: emission.category,
-                    data_source: emission.data_source.clone(),
-                    balanced: emission.balanced,
-                    date: emission.date,
-                    value: emission.value,
+        /// List an owner's Assets with at least one own CO2 Emissions item that is not balanced.
+        ///
+        /// Returns empty vector if an account does not own any such Assets.
+        /// Only the `MAX_SCAN_LENGTH` owned Assets with the lowest ids are examined
+        /// and at most `MAX_LIST_RETURN` of them are returned.
+        ///
+        /// # Arguments
+        ///
+        /// * `owner` - An account for whom to query assets.
+        ///
+        #[ink(message)]
+        pub fn assets_with_unbalanced(&self, owner: AccountId) -> Vec<AssetId> {
+            self.owned_assets
+                .get(&owner)
+                .into_iter()
+                .flatten()
+                .take(MAX_SCAN_LENGTH as usize)
+                .copied()
+                .filter(|id| {
+                    self.co2_emissions
+                        .get(id)
+                        .unwrap_or_default()
+                        .iter()
+                        .any(|item| !item.balanced)
                 })
-            });
+                .take(MAX_LIST_RETURN.into())
+                .collect()
         }
 
-        /// Return the next id and increase by 1.
-        fn next_id(&mut self) -> Result<AssetId, AssetCO2EmissionsError> {
-            let asset_id = self.next_id;
-            self.next_id = self
-                .next_id
-                .checked_add(1)
-                .ok_or(AssetCO2EmissionsError::AssetIdOverflow)?;
-            Ok(asset_id)
-        }
+        /// Set stopped state for several Assets at once.
+        /// All Assets are validated before any of them gets paused,
+        /// so the call fails as a whole if any Asset cannot be paused.
+        ///
+        /// # Arguments
+        ///
+        /// * `ids` - The Assets to lock.
+        ///
+        /// # Errors
+        ///
+        /// * `AlreadyPaused` - When any Asset is already paused or listed more than once.
+        /// * `AlreadyRetired` - When any Asset is retired.
+        /// * `AssetNotFound` - When any Asset does not exist.
+        /// * `NotOwner` - When transaction sender is not an owner of any Asset.
+        ///
+        /// # Events
+        ///
+        /// * `Paused` - When asset gets paused. Emitted per each Asset.
+        ///
+        #[ink(message)]
+        pub fn pause_batch(&mut self, ids: Vec<AssetId>) -> Result<(), AssetCO2EmissionsError> {
+            let caller = self.env().caller();
 
-        /// Build Asset tree from child to parent.
-        fn build_asset_tree(&self, id: AssetId) -> Vec<AssetDetails> {
-            let mut asset_id = id;
-            let mut tree_path: Vec<AssetDetails> = Vec::new();
-            loop {
-                // This function is called after initial check if asset exists
-                // So it must contain asset and its children -- unwrap must be safe
-                // It has been confirmed in previous test cases
-                // If not, we need to capture that sth is wrong with the smart contract
-                let asset: AssetDetails = self
-                    .get_asset(asset_id)
-                    .expect("Asset existence already checked");
-                let parent_details = asset.parent;
-                tree_path.push(asset);
-                match parent_details {
-                    None => break,
-                    Some(parent_id) => asset_id = parent_id,
+            let mut batch = BTreeSet::new();
+            for id in ids.iter() {
+                self.ensure_owner(id, &caller)?;
+                self.ensure_not_paused(id)?;
+                if !batch.insert(*id) {
+                    return Err(AssetCO2EmissionsError::AlreadyPaused);
                 }
             }
 
-            tree_path
+            for id in ids {
+                self.paused.insert(id, &true);
+                self.env().emit_event(Paused { id });
+            }
+
+            Ok(())
         }
-    }
 
-    impl AssetCO2Emissions for InfinityAsset {
+        /// Find the lowest common ancestor of two Assets.
+        /// An Asset is considered its own ancestor, so if one Asset is an ancestor of the other it is returned.
+        ///
+        /// Returns None if either Asset does not exist or the Assets are unrelated.
+        ///
+        /// # Arguments
+        ///
+        /// * `a` - The first Asset id.
+        /// * `b` - The second Asset id.
+        ///
         #[ink(message)]
-        fn list_assets(&self, owner: AccountId) -> Vec<AssetId> {
-            match self.owned_assets.get(&owner) {
-                None => Vec::new(),
-                Some(owned_assets) => owned_assets.iter().copied().collect::<Vec<AssetId>>(),
-            }
+        pub fn common_ancestor(&self, a: AssetId, b: AssetId) -> Option<AssetId> {
+            self.ensure_recorded(&a).ok()?;
+            self.ensure_recorded(&b).ok()?;
+
+            let ancestors: BTreeSet<AssetId> = self.lineage(a).into_iter().collect();
+            self.lineage(b)
+                .into_iter()
+                .find(|asset_id| ancestors.contains(asset_id))
         }
 
+        /// Get the split of an Asset's footprint into CO2 Emissions inherited from its ancestors
+        /// and CO2 Emissions added to the Asset itself, as `(inherited, added)`.
+        /// Both values are summed with saturating arithmetic.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
         #[ink(message)]
-        fn owner_of(&self, id: AssetId) -> Option<AccountId> {
-            self.asset_owner.get(id)
+        pub fn inherited_vs_added(&self, id: AssetId) -> Option<(u128, u128)> {
+            self.ensure_recorded(&id).ok()?;
+            let own = self.own_emissions_total(id);
+            let inherited = self.total_emissions(id).saturating_sub(own);
+            Some((inherited, own))
         }
 
+        /// Get the number of distinct Data Sources used by CO2 Emissions of an Asset and all its ancestors.
+        /// The parent chain is walked once and stops on any repeated Asset id.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
         #[ink(message)]
-        fn blast(
-            &mut self,
-            to: AccountId,
-            metadata: Metadata,
-            emissions: Vec<CO2Emissions>,
-            parent: ParentDetails,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            let caller = self.env().caller();
-
-            self.ensure_proper_metadata(&metadata)?;
-            self.ensure_emissions_correct(None, &emissions)?;
-            self.ensure_proper_parent(&parent, &caller)?;
-
-            let asset_id: u128 = self.next_id()?;
-            self.ensure_not_exist(&asset_id)?;
-
-            self.insert_owned_asset(&to, &asset_id)?;
-
-            self.asset_owner.insert(asset_id, &to);
-            self.metadata.insert(asset_id, &metadata);
-            self.paused.insert(asset_id, &false);
-            self.parent.insert(asset_id, &parent);
-
-            self.env().emit_event(Blasted {
-                id: asset_id,
-                metadata,
-                owner: to,
-                parent,
-            });
-
-            // Save CO2 Emissions & emit corresponding events.
-            self.save_new_co2_emissions(&asset_id, &emissions);
+        pub fn distinct_data_source_count(&self, id: AssetId) -> Option<u32> {
+            self.ensure_recorded(&id).ok()?;
+            let data_sources: BTreeSet<DataSource> = self
+                .lineage_emissions(id)
+                .into_iter()
+                .map(|item| item.data_source)
+                .collect();
+            Some(data_sources.len() as u32)
+        }
 
-            Ok(())
+        /// Get the emissions-weighted average date of an Asset's own CO2 Emissions,
+        /// i.e. `sum(value * date) / sum(value)` computed in `u128`.
+        /// The result is rounded down to a whole second.
+        ///
+        /// Returns None if Asset does not exist or the weighted sum overflows `u128`.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn weighted_emission_date(&self, id: AssetId) -> Option<u64> {
+            let (weighted_dates, total) = self.get_asset_emissions(id)?.iter().try_fold(
+                (0u128, 0u128),
+                |(weighted_dates, total), item| {
+                    let weighted_date = item.value.checked_mul(item.date.into())?;
+                    Some((
+                        weighted_dates.checked_add(weighted_date)?,
+                        total.checked_add(item.value)?,
+                    ))
+                },
+            )?;
+            let date = weighted_dates.checked_div(total)?;
+            // The weighted average never exceeds the latest date, so it fits into `u64`.
+            u64::try_from(date).ok()
         }
 
+        /// Get CO2 Emissions of an Asset and all its ancestors summed per category and balanced flag.
+        /// Only combinations with non-zero totals are returned, ordered by category and then by
+        /// the balanced flag (unbalanced first). Totals saturate at `u128::MAX`.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
         #[ink(message)]
-        fn transfer(
-            &mut self,
-            to: AccountId,
+        pub fn emissions_matrix(
+            &self,
             id: AssetId,
-            emissions: Vec<CO2Emissions>,
-        ) -> Result<(), AssetCO2EmissionsError> {
-            let from = self.env().caller();
-
-            self.ensure_exists(&id)?;
-            self.ensure_owner(&id, &from)?;
-            self.ensure_not_paused(&id)?;
-            self.ensure_emissions_correct(Some(id), &emissions)?;
-
-            self.remove_owned_asset(&from, &id)?;
-            self.insert_owned_asset(&to, &id)?;
-
-            self.asset_owner.insert(id, &to);
-
-            self.env().emit_event(Transfer { from, to, id });
-
-            // Save CO2 Emissions & emit corresponding events.
-            self.save_new_co2_emissions(&id, &emissions);
-
-            Ok(())
+        ) -> Option<Vec<(EmissionsCategory, bool, u128)>> {
+            self.ensure_recorded(&id).ok()?;
+            let emissions = self.lineage_emissions(id);
+
+            let mut matrix = Vec::new();
+            for category in [
+                EmissionsCategory::Process,
+                EmissionsCategory::Transport,
+                EmissionsCategory::Upstream,
+            ] {
+                for balanced in [false, true] {
+                    let total = emissions
+                        .iter()
+                        .filter(|item| item.category == category && item.balanced == balanced)
+                        .fold(0u128, |total, item| total.saturating_add(item.value));
+                    if total > 0 {
+                        matrix.push((category, balanced, total));
+                    }
+                }
+            }
+            Some(matrix)
         }
 
+        /// Get the median value of an Asset's own CO2 Emissions.
+        ///
+        /// Values are sorted ascending; for an even number of records
+        /// the lower of the two middle values is returned.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
         #[ink(message)]
-        fn pause(&mut self, id: AssetId) -> Result<(), AssetCO2EmissionsError> {
-            self.ensure_owner(&id, &self.env().caller())?;
-            self.ensure_not_paused(&id)?;
-
-            self.paused.insert(id, &true);
-            self.env().emit_event(Paused { id });
+        pub fn median_emission(&self, id: AssetId) -> Option<u128> {
+            let mut values: Vec<u128> = self
+                .get_asset_emissions(id)?
+                .iter()
+                .map(|item| item.value)
+                .collect();
+            values.sort_unstable();
+            values.get(values.len().saturating_sub(1) / 2).copied()
+        }
 
-            Ok(())
+        /// Get an Asset's own CO2 Emissions recorded with the given lot identifier.
+        /// Records without a lot identifier belong to lot 0.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        /// * `lot_id` - The batch/lot identifier to filter by.
+        ///
+        #[ink(message)]
+        pub fn emissions_by_lot(&self, id: AssetId, lot_id: u64) -> Option<Vec<CO2Emissions>> {
+            Some(
+                self.get_asset_emissions(id)?
+                    .into_iter()
+                    .filter(|item| item.lot_id == lot_id)
+                    .collect(),
+            )
         }
 
+        /// Check if an Asset can be used by `who` as a parent when blasting a child Asset (e.g. splitting).
+        /// Bundles all preconditions checked by `blast` for the parent:
+        /// the Asset exists, `who` owns it, it is `Paused`, it has less than `MAX_CHILDREN_PER_ASSET` children
+        /// and a child would not exceed `MAX_TREE_DEPTH`.
+        ///
+        /// # Arguments
+        ///
+        /// * `who` - The account that wants to blast a child Asset.
+        /// * `id` - The Asset id.
+        ///
         #[ink(message)]
-        fn has_paused(&self, id: AssetId) -> Option<bool> {
-            self.paused.get(id)
+        pub fn can_be_parent(&self, who: AccountId, id: AssetId) -> bool {
+            self.ensure_can_be_parent(&id, &who).is_ok()
         }
 
+        /// Declares the quantity of an Asset, limiting the sum of relations of its child Assets.
+        /// Child Assets get their relation to the parent as their quantity when blasted
+        /// and cannot declare a quantity above it.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        /// * `quantity` - The Asset's quantity.
+        ///
+        /// # Errors
+        ///
+        /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `NotOwner` - When the caller is not the Asset owner.
+        /// * `ParentQuantityExceeded` - When child Assets already use more than `quantity`,
+        ///                              or `quantity` exceeds the Asset's relation to its parent.
+        ///
         #[ink(message)]
-        fn add_emissions(
+        pub fn set_quantity(
             &mut self,
             id: AssetId,
-            emissions: CO2Emissions,
+            quantity: ParentRelation,
         ) -> Result<(), AssetCO2EmissionsError> {
-            self.ensure_exists(&id)?;
             self.ensure_owner(&id, &self.env().caller())?;
-            self.ensure_not_paused(&id)?;
-
-            let emissions: Vec<CO2Emissions> = Vec::from([emissions]);
-            self.ensure_emissions_correct(Some(id), &emissions)?;
-
-            // Save CO2 Emissions & emit corresponding events.
-            self.save_new_co2_emissions(&id, &emissions);
+            if self.split_quantity.get(id).unwrap_or(0) > quantity {
+                return Err(AssetCO2EmissionsError::ParentQuantityExceeded);
+            }
+            if let Some((_, relation)) = self.parent.get(id).flatten() {
+                if quantity > relation {
+                    return Err(AssetCO2EmissionsError::ParentQuantityExceeded);
+                }
+            }
+            self.quantity.insert(id, &quantity);
             Ok(())
         }
 
+        /// Get the declared quantity of an Asset.
+        ///
+        /// Returns None if Asset does not exist or has no declared quantity.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
         #[ink(message)]
-        fn get_asset_emissions(&self, id: AssetId) -> Option<Vec<CO2Emissions>> {
-            self.co2_emissions.get(id)
+        pub fn quantity_of(&self, id: AssetId) -> Option<ParentRelation> {
+            self.quantity.get(id)
         }
 
+        /// Sets the rate limit of CO2 Emissions items added to a single Asset via `add_emissions`.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `max_emissions_per_window` - Maximum number of items per Asset in a single window, 0 disables the limit.
+        /// * `window_seconds` - Length of the window in seconds.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
         #[ink(message)]
-        fn get_metadata(&self, id: AssetId) -> Option<Metadata> {
-            self.metadata.get(id)
+        pub fn set_emission_rate_limit(
+            &mut self,
+            max_emissions_per_window: u32,
+            window_seconds: u64,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.max_emissions_per_window = max_emissions_per_window;
+            self.window_seconds = window_seconds;
+            Ok(())
         }
 
+        /// Get the total of a single CO2 Emissions category across an Asset and all its ancestors.
+        /// Totals saturate at `u128::MAX`.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        /// * `category` - The CO2 Emissions category to sum.
+        ///
         #[ink(message)]
-        fn get_parent_details(&self, id: AssetId) -> Option<ParentDetails> {
-            self.parent.get(id)
+        pub fn category_total_in_lineage(
+            &self,
+            id: AssetId,
+            category: EmissionsCategory,
+        ) -> Option<u128> {
+            self.ensure_recorded(&id).ok()?;
+            Some(
+                self.lineage_emissions(id)
+                    .iter()
+                    .filter(|item| item.category == category)
+                    .fold(0, |total, item| total.saturating_add(item.value)),
+            )
         }
 
+        /// Export an Asset's own CO2 Emissions as CSV-like text bytes.
+        ///
+        /// Each record is a row `category,value,date,balanced,data_source_hex`, e.g.
+        /// `Upstream,10,1682632800,true,00010203`; rows are separated by a newline (`\n`)
+        /// and the data source is lowercase hex without a prefix.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
         #[ink(message)]
-        fn get_asset(&self, id: AssetId) -> Option<AssetDetails> {
-            match self.get_metadata(id) {
-                // Asset does not exist, return None.
-                None => None,
-                // Asset must exist, fetch and unpack attributes.
-                Some(metadata) => {
-                    let emissions = self.get_asset_emissions(id).expect("Emissions must exist");
-                    let parent = self
-                        .get_parent_details(id)
-                        .expect("Parent Details must exist");
-
-                    Some(AssetDetails {
-                        asset_id: id,
-                        metadata,
-                        emissions,
-                        parent,
-                    })
+        pub fn export_emissions_csv(&self, id: AssetId) -> Option<Vec<u8>> {
+            const HEX: &[u8; 16] = b"0123456789abcdef";
+
+            let mut csv = Vec::new();
+            for (i, item) in self.get_asset_emissions(id)?.iter().enumerate() {
+                if i > 0 {
+                    csv.push(b'\n');
+                }
+                let category: &[u8] = match item.category {
+                    EmissionsCategory::Process => b"Process",
+                    EmissionsCategory::Transport => b"Transport",
+                    EmissionsCategory::Upstream => b"Upstream",
+                };
+                csv.extend_from_slice(category);
+                csv.extend_from_slice(
+                    format!(",{},{},{},", item.value, item.date, item.balanced).as_bytes(),
+                );
+                for byte in &item.data_source {
+                    csv.push(HEX[usize::from(byte >> 4)]);
+                    csv.push(HEX[usize::from(byte & 0x0f)]);
                 }
             }
+            Some(csv)
         }
 
+        /// Check if adding `additional` CO2 Emissions items to an Asset would exceed
+        /// the maximum number of CO2 Emissions per Asset (`MAX_EMISSIONS_PER_ASSET`).
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        /// * `additional` - Number of CO2 Emissions items to be added.
+        ///
         #[ink(message)]
-        fn query_emissions(&self, id: AssetId) -> Option<Vec<AssetDetails>> {
-            match self.ensure_exists(&id) {
-                Err(_) => None,
-                Ok(_) => Some(self.build_asset_tree(id)),
-            }
+        pub fn would_exceed_cap(&self, id: AssetId, additional: u32) -> Option<bool> {
+            let current = self.get_asset_emissions(id)?.len() as u32;
+            Some(current.saturating_add(additional) > u32::from(MAX_EMISSIONS_PER_ASSET))
         }
-    }
-
-    /// Unit tests
-    #[cfg(test)]
-    mod tests {
-        use ink::env::test;
-        use ink::env::test::DefaultAccounts;
-        use ink::env::DefaultEnvironment;
 
-        use super::*;
+        /// Blast an Asset with an id derived from the caller and a nonce instead of the next sequential id.
+        /// Replaying the same call (same caller and nonce) fails instead of minting a duplicate Asset,
+        /// which makes minting idempotent e.g. for bridges retrying events.
+        /// The id is the first 16 bytes of `Blake2x256(caller || nonce)`, with the nonce SCALE encoded.
+        ///
+        /// # Arguments
+        ///
+        /// * `to` - The owner of the new Asset.
+        /// * `metadata` - Asset's metadata.
+        /// * `emissions` - CO2 Emissions of the Asset.
+        /// * `parent` - Optional parent details, as in `blast`.
+        /// * `nonce` - Caller-chosen value identifying the mint.
+        ///
+        /// # Errors
+        ///
+        /// * `AssetAlreadyExists` - When an Asset with the derived id already exists (e.g. on replay).
+        /// * `InsufficientDeposit` - When the transferred value does not cover the blast fee and the storage deposit.
+        /// * `InsufficientFee` - When the transferred value is lower than the blast fee.
+        /// * `MintingSealed` - When minting has been permanently disabled.
+        /// * `TransferFailed` - When transferring the blast fee to the contract owner fails.
+        /// * All errors returned by `blast` for invalid metadata, emissions or parent.
+        ///
+        /// # Events
+        ///
+        /// * `Blasted` - When an Asset gets blasted.
+        /// * `Emissions` - When CO2 Emissions are added. Emitted per each CO2 Emission item.
+        ///
+        #[ink(message, payable)]
+        pub fn blast_deterministic(
+            &mut self,
+            to: AccountId,
+            metadata: Metadata,
+            emissions: Vec<CO2Emissions>,
+            parent: ParentDetails,
+            nonce: u64,
+        ) -> Result<AssetId, AssetCO2EmissionsError> {
+            let caller = self.env().caller();
 
-        use ink::primitives::{Clear, Hash};
+            self.ensure_minting_not_sealed()?;
+            self.ensure_blast_fee_paid(1)?;
+            self.ensure_storage_deposit_paid(1)?;
+            self.ensure_proper_metadata(&metadata)?;
+            self.ensure_emissions_correct(None, &emissions)?;
 
-        type Event = <InfinityAsset as ::ink::reflect::ContractEventBase>::Type;
+            let asset_id = self.deterministic_id(&caller, nonce);
+            self.ensure_proper_parent(&parent, &caller, asset_id)?;
+            self.ensure_not_exist(&asset_id)?;
 
-        fn get_accounts() -> DefaultAccounts<DefaultEnvironment> {
-            test::default_accounts::<DefaultEnvironment>()
+            self.mint_asset(asset_id, to, metadata, emissions, parent)?;
+            self.pay_blast_fee(1)?;
+            Ok(asset_id)
         }
 
-        fn prepare_env() -> (DefaultAccounts<DefaultEnvironment>, InfinityAsset) {
-            (get_accounts(), InfinityAsset::new())
+        /// Blast many Assets owned by `to` in one call, e.g. billets cut at once.
+        /// Every item is validated before any state is changed, so a single invalid item rejects the whole batch.
+        /// Assets get sequential ids in the input order; the blast fee is due for each of them.
+        ///
+        /// # Arguments
+        ///
+        /// * `to` - The owner of the new Assets.
+        /// * `items` - Metadata, CO2 Emissions and optional parent details of each Asset, as in `blast`.
+        ///
+        /// # Errors
+        ///
+        /// * `AssetIdOverflow` - When the batch would exceed the maximum Asset id.
+        /// * `BatchOverflow` - When the batch exceeds `MAX_BLAST_BATCH` items.
+        /// * `InsufficientDeposit` - When the transferred value does not cover the blast fees and storage deposits of all Assets.
+        /// * `InsufficientFee` - When the transferred value is lower than the blast fee of all Assets.
+        /// * `MintingSealed` - When minting has been permanently disabled.
+        /// * `ParentQuantityExceeded` - When children in the batch together split more than the parent quantity.
+        /// * `TransferFailed` - When transferring the blast fees to the contract owner fails.
+        /// * All errors returned by `blast` for invalid metadata, emissions or parent.
+        ///
+        /// # Events
+        ///
+        /// * `Blasted` - When an Asset gets blasted. Emitted per each Asset.
+        /// * `Emissions` - When CO2 Emissions are added. Emitted per each CO2 Emission item.
+        ///
+        #[ink(message, payable)]
+        pub fn blast_batch(
+            &mut self,
+            to: AccountId,
+            items: Vec<(Metadata, Vec<CO2Emissions>, ParentDetails)>,
+        ) -> Result<Vec<AssetId>, AssetCO2EmissionsError> {
+            let caller = self.env().caller();
+
+            if items.len() > MAX_BLAST_BATCH.into() {
+                return Err(AssetCO2EmissionsError::BatchOverflow);
+            }
+            let count = items.len() as u32;
+            self.ensure_minting_not_sealed()?;
+            self.ensure_blast_fee_paid(count)?;
+            self.ensure_storage_deposit_paid(count)?;
+            if self.next_id < self.id_high_water {
+                return Err(AssetCO2EmissionsError::IdRegression);
+            }
+            self.next_id
+                .checked_add(count.into())
+                .ok_or(AssetCO2EmissionsError::AssetIdOverflow)?;
+
+            // Parent quantity split and children blasted by the preceding items of the batch.
+            let mut batch_splits: BTreeMap<AssetId, (ParentRelation, u32)> = BTreeMap::new();
+            for (index, (metadata, emissions, parent)) in items.iter().enumerate() {
+                let asset_id = self.next_id + index as AssetId;
+                self.ensure_proper_metadata(metadata)?;
+                self.ensure_emissions_correct(None, emissions)?;
+                self.ensure_proper_parent(parent, &caller, asset_id)?;
+                self.ensure_not_exist(&asset_id)?;
+
+                if let Some((parent_id, relation)) = parent {
+                    let (split, children) = batch_splits.entry(*parent_id).or_insert((0, 0));
+                    *split = split
+                        .checked_add(*relation)
+                        .ok_or(AssetCO2EmissionsError::ParentQuantityExceeded)?;
+                    *children += 1;
+                    self.ensure_parent_quantity_available(parent_id, *split)?;
+                    self.ensure_children_available(parent_id, *children)?;
+                }
+            }
+
+            let mut ids = Vec::with_capacity(items.len());
+            for (metadata, emissions, parent) in items {
+                let asset_id = self.next_id()?;
+                self.mint_asset(asset_id, to, metadata, emissions, parent)?;
+                ids.push(asset_id);
+            }
+            self.pay_blast_fee(count)?;
+            Ok(ids)
         }
 
-        fn env_with_default_asset() -> (
-            (DefaultAccounts<DefaultEnvironment>, InfinityAsset),
-            (AssetId, AccountId),
-        ) {
-            let (accounts, mut contract) = prepare_env();
-            let asset_owner = accounts.django;
-            let asset_id = blast_default_asset(&mut contract, &asset_owner);
-            ((accounts, contract), (asset_id, asset_owner))
+        /// Rank Asset owners by the total of their Assets' own CO2 Emissions, highest first.
+        /// Inherited CO2 Emissions of ancestors are not counted, so each record is attributed
+        /// to exactly one owner. Owners with equal totals keep the account order.
+        ///
+        /// Totals are read from the maintained per-owner accumulator, so the cost grows with the number
+        /// of owners only. The number of returned owners is capped at `MAX_LIST_RETURN`.
+        ///
+        /// # Arguments
+        ///
+        /// * `limit` - Maximum number of owners to return.
+        ///
+        #[ink(message)]
+        pub fn top_emitters(&self, limit: u32) -> Vec<(AccountId, u128)> {
+            let mut totals: Vec<(AccountId, u128)> = self
+                .owned_assets
+                .iter()
+                .filter(|(_, assets)| !assets.is_empty())
+                .map(|(owner, _)| (*owner, self.get_owner_emissions(*owner)))
+                .collect();
+            totals.sort_by_key(|(_, total)| core::cmp::Reverse(*total));
+            totals.truncate(limit.min(MAX_LIST_RETURN.into()) as usize);
+            totals
         }
 
-        fn set_caller(sender: AccountId) {
-            test::set_caller::<DefaultEnvironment>(sender);
+        /// Get the total of own CO2 Emissions of all Assets currently owned by an account.
+        ///
+        /// New CO2 Emissions are credited to the Asset's owner at the time they are added.
+        /// On transfer, the Asset's own CO2 Emissions total moves from the previous to the new owner;
+        /// CO2 Emissions inherited from ancestors are not moved, they stay attributed to the ancestors' owners.
+        /// Totals saturate at `u128::MAX`.
+        ///
+        /// Returns 0 if an account does not own any Assets.
+        ///
+        /// # Arguments
+        ///
+        /// * `owner` - The account.
+        ///
+        #[ink(message)]
+        pub fn get_owner_emissions(&self, owner: AccountId) -> u128 {
+            self.owner_emissions.get(owner).unwrap_or(0)
         }
 
-        fn new_emission(
-            category: EmissionsCategory,
-            data_source: DataSource,
-            balanced: bool,
-            value: u128,
-            date: u64,
-        ) -> CO2Emissions {
-            CO2Emissions {
-                category,
-                data_source,
-                balanced,
-                value,
-                date,
-            }
+        /// Get CO2 Emissions of an Asset and all its ancestors as a single list,
+        /// each item tagged with the id of the Asset it belongs to.
+        /// Items are ordered from the Asset itself up to the root, as in `query_emissions`.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn flattened_lineage_emissions(
+            &self,
+            id: AssetId,
+        ) -> Option<Vec<(AssetId, CO2Emissions)>> {
+            self.ensure_recorded(&id).ok()?;
+            Some(
+                self.lineage(id)
+                    .into_iter()
+                    .flat_map(|asset_id| {
+                        self.co2_emissions
+                            .get(asset_id)
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(move |item| (asset_id, item))
+                    })
+                    .collect(),
+            )
         }
 
-        fn default_data_source() -> Vec<u8> {
-            Vec::from([0u8, 1u8, 2u8, 3u8])
+        /// Check which Asset ids in a range exist, e.g. to find gaps left by failed blasts.
+        ///
+        /// # Arguments
+        ///
+        /// * `start` - The first Asset id to check.
+        /// * `limit` - Number of consecutive ids to check, capped at `MAX_SCAN_LENGTH`.
+        ///
+        #[ink(message)]
+        pub fn storage_presence_range(&self, start: AssetId, limit: u32) -> Vec<(AssetId, bool)> {
+            let end = start.saturating_add(limit.min(MAX_SCAN_LENGTH).into());
+            (start..end)
+                .map(|id| (id, self.asset_owner.contains(id)))
+                .collect()
         }
 
-        fn new_emissions(items: u8) -> Vec<CO2Emissions> {
-            let mut emissions = Vec::new();
-            for i in 0..items {
-                emissions.push(new_emission(
-                    EmissionsCategory::Upstream,
-                    default_data_source(),
-                    true,
-                    i as u128 + 1, // avoid Zero Emissions Item
-                    default_timestamp(),
-                ));
-            }
-            emissions
+        /// Sets the weight of an Emissions category used by `weighted_footprint`.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `category` - The Emissions category.
+        /// * `weight` - The weight in basis points (10000 means 1.0x).
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn set_category_weight(
+            &mut self,
+            category: EmissionsCategory,
+            weight: u32,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.category_weights.insert(category, &weight);
+            Ok(())
         }
 
-        fn default_metadata() -> Vec<u8> {
-            Vec::from([0u8, 1u8, 2u8, 3u8])
+        /// Get the weight of an Emissions category in basis points.
+        /// Categories without a configured weight use `DEFAULT_CATEGORY_WEIGHT` (1.0x).
+        ///
+        /// # Arguments
+        ///
+        /// * `category` - The Emissions category.
+        ///
+        #[ink(message)]
+        pub fn category_weight(&self, category: EmissionsCategory) -> u32 {
+            self.category_weights
+                .get(category)
+                .unwrap_or(DEFAULT_CATEGORY_WEIGHT)
         }
 
-        fn default_timestamp() -> u64 {
-            // 28.04.2023 00:00:00
-            1682632800
+        /// Get a composite footprint score of an Asset and all its ancestors,
+        /// with each category's total multiplied by the category weight.
+        ///
+        /// Values are summed per category first, then weighted as `total * weight / 10000`
+        /// (rounded down), so rounding happens once per category. All arithmetic saturates at `u128::MAX`.
+        /// With default weights the result equals the plain total of CO2 Emissions.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn weighted_footprint(&self, id: AssetId) -> Option<u128> {
+            self.ensure_recorded(&id).ok()?;
+            let emissions = self.lineage_emissions(id);
+
+            let mut footprint: u128 = 0;
+            for category in [
+                EmissionsCategory::Process,
+                EmissionsCategory::Transport,
+                EmissionsCategory::Upstream,
+            ] {
+                let total = emissions
+                    .iter()
+                    .filter(|item| item.category == category)
+                    .fold(0u128, |total, item| total.saturating_add(item.value));
+                let weighted = total.saturating_mul(self.category_weight(category).into())
+                    / u128::from(DEFAULT_CATEGORY_WEIGHT);
+                footprint = footprint.saturating_add(weighted);
+            }
+            Some(footprint)
         }
 
-        fn default_emission_item() -> CO2Emissions {
-            let emissions_category = EmissionsCategory::Upstream;
-            let emissions_data_source = default_data_source();
-            let emissions_balanced = true;
-            let emissions_value = 1;
-            let timestamp: u64 = default_timestamp();
+        /// Get CO2 Emissions recorded by the transfer that moved an Asset to its current owner.
+        /// CO2 Emissions added afterwards with `add_emissions` are not included.
+        ///
+        /// Returns None if Asset does not exist.
+        /// Returns empty vector if the Asset has never been transferred.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn current_ownership_emissions(&self, id: AssetId) -> Option<Vec<CO2Emissions>> {
+            let emissions = self.get_asset_emissions(id)?;
+            let (start, len) = self.last_transfer_emissions.get(id).unwrap_or((0, 0));
+            Some(
+                emissions
+                    .into_iter()
+                    .skip(start as usize)
+                    .take(len as usize)
+                    .collect(),
+            )
+        }
 
-            new_emission(
-                emissions_category,
-                emissions_data_source,
-                emissions_balanced,
-                emissions_value,
-                timestamp,
+        /// Get indices of an Asset's own CO2 Emissions items with an empty Data Source.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn emissions_missing_source(&self, id: AssetId) -> Option<Vec<u32>> {
+            Some(
+                self.get_asset_emissions(id)?
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| item.data_source.is_empty())
+                    .map(|(index, _)| index as u32)
+                    .collect(),
             )
         }
 
-        fn blast_default_asset(contract: &mut InfinityAsset, owner: &AccountId) -> AssetId {
-            let metadata = default_metadata();
-            let parent = None;
+        /// Sets whether an Asset gets paused (for review) when it reaches `MAX_EMISSIONS_PER_ASSET`.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `enabled` - If true, recording the CO2 Emission item that reaches the cap pauses the Asset
+        ///               and emits `Paused`.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn set_auto_pause_on_cap(
+            &mut self,
+            enabled: bool,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.auto_pause_on_cap = enabled;
+            Ok(())
+        }
 
-            let emissions: Vec<CO2Emissions> = new_emissions(1);
+        /// Sets whether CO2 Emissions items must have a non-empty Data Source.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `required` - If true, CO2 Emissions items with an empty Data Source are rejected.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn set_require_data_source(
+            &mut self,
+            required: bool,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.require_data_source = required;
+            Ok(())
+        }
 
-            assert!(contract.blast(*owner, metadata, emissions, parent).is_ok());
+        /// Get the tree of an Asset and all its ancestors as an adjacency list of `(child, parent)` edges,
+        /// ordered from the Asset up to the root. A root Asset has no edges.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn lineage_edges(&self, id: AssetId) -> Option<Vec<(AssetId, AssetId)>> {
+            self.ensure_recorded(&id).ok()?;
+            Some(
+                self.lineage(id)
+                    .windows(2)
+                    .map(|edge| (edge[0], edge[1]))
+                    .collect(),
+            )
+        }
 
-            contract.next_id - 1
+        /// Sets the fee that has to be paid for blasting an Asset.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `fee` - The blast fee, 0 makes blasting free.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn set_blast_fee(&mut self, fee: Balance) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.blast_fee = fee;
+            Ok(())
         }
 
-        /// For calculating the event topic hash.
-        struct PrefixedValue<'a, 'b, T> {
-            pub prefix: &'a [u8],
-            pub value: &'b T,
+        /// Get the fee that has to be paid for blasting an Asset.
+        #[ink(message)]
+        pub fn blast_fee(&self) -> Balance {
+            self.blast_fee
         }
 
-        impl<X> scale::Encode for PrefixedValue<'_, '_, X>
-        where
-            X: scale::Encode,
-        {
-            #[inline]
-            fn size_hint(&self) -> usize {
-                self.prefix.size_hint() + self.value.size_hint()
-            }
-
-            #[inline]
-            fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
-                self.prefix.encode_to(dest);
-                self.value.encode_to(dest);
-            }
+        /// Sets the deposit that has to be paid with each blasted Asset, on top of the blast fee.
+        /// The deposit is held by the contract and refunded to the owner retiring the Asset.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `deposit` - The storage deposit, 0 disables it. Applies to Assets blasted afterwards.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn set_storage_deposit(
+            &mut self,
+            deposit: Balance,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.storage_deposit = deposit;
+            Ok(())
         }
 
-        fn encoded_into_hash<T>(entity: &T) -> Hash
-        where
-            T: scale::Encode,
-        {
-            use ink::{
-                env::hash::{Blake2x256, CryptoHash, HashOutput},
-                primitives::Clear,
-            };
+        /// Get the deposit that has to be paid with each blasted Asset.
+        #[ink(message)]
+        pub fn storage_deposit(&self) -> Balance {
+            self.storage_deposit
+        }
 
-            let mut result = Hash::CLEAR_HASH;
-            let len_result = result.as_ref().len();
-            let encoded = entity.encode();
-            let len_encoded = encoded.len();
-            if len_encoded <= len_result {
-                result.as_mut()[..len_encoded].copy_from_slice(&encoded);
-                return result;
+        /// Withdraws funds (e.g. accumulated fees) from the contract to the contract owner.
+        /// Storage deposits held for Assets cannot be withdrawn.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `amount` - The amount to withdraw.
+        ///
+        /// # Errors
+        ///
+        /// * `InsufficientBalance` - When the contract balance without held deposits is lower than `amount`.
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        /// * `TransferFailed` - When transferring the funds to the contract owner fails.
+        ///
+        /// # Events
+        ///
+        /// * `Withdrawn` - When funds get withdrawn.
+        ///
+        #[ink(message)]
+        pub fn withdraw(&mut self, amount: Balance) -> Result<(), AssetCO2EmissionsError> {
+            let caller = self.env().caller();
+            self.ensure_contract_owner(caller)?;
+            if self.env().balance().saturating_sub(self.held_deposits) < amount {
+                return Err(AssetCO2EmissionsError::InsufficientBalance);
             }
-            let mut hash_output = <<Blake2x256 as HashOutput>::Type as Default>::default();
-            <Blake2x256 as CryptoHash>::hash(&encoded, &mut hash_output);
-            let copy_len = core::cmp::min(hash_output.len(), len_result);
-            result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
-            result
+
+            self.env()
+                .transfer(caller, amount)
+                .map_err(|_| AssetCO2EmissionsError::TransferFailed)?;
+            self.env().emit_event(Withdrawn { to: caller, amount });
+            Ok(())
         }
 
-        fn assert_blasted_event(
-            event: &test::EmittedEvent,
-            expected_id: AssetId,
-            expected_metadata: Metadata,
-            expected_owner: AccountId,
-            expected_parent: ParentDetails,
-        ) {
-            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
-                .expect("Encountered invalid contract event data buffer");
-            if let Event::Blasted(Blasted {
-                id,
-                metadata,
-                owner,
-                parent,
-            }) = decoded_event
-            {
-                assert_eq!(id, expected_id, "encountered invalid Blasted.id");
-                assert_eq!(
-                    metadata, expected_metadata,
-                    "encountered invalid Blasted.metadata"
-                );
-                assert_eq!(owner, expected_owner, "encountered invalid Blasted.owner");
-                assert_eq!(
-                    parent, expected_parent,
-                    "encountered invalid Blasted.parent"
-                );
-            } else {
-                panic!("encountered unexpected event kind: expected a Blasted event")
-            }
-            let expected_topics = vec![
-                encoded_into_hash(&PrefixedValue {
-                    value: b"InfinityAsset::Blasted",
-                    prefix: b"",
-                }),
-                encoded_into_hash(&PrefixedValue {
-                    prefix: b"InfinityAsset::Blasted::id",
-                    value: &expected_id,
-                }),
-            ];
-            assert_event_topics(expected_topics, event.topics.clone());
+        /// Get CO2 Emissions an Asset has been blasted with ("as-blasted").
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn creation_emissions(&self, id: AssetId) -> Option<Vec<CO2Emissions>> {
+            let mut emissions = self.get_asset_emissions(id)?;
+            emissions.truncate(self.creation_emissions_count.get(id).unwrap_or(0) as usize);
+            Some(emissions)
         }
 
-        fn assert_paused_event(event: &test::EmittedEvent, expected_id: AssetId) {
-            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
-                .expect("Encountered invalid contract event data buffer");
-            if let Event::Paused(Paused { id }) = decoded_event {
-                assert_eq!(id, expected_id, "encountered invalid Paused.id");
-            } else {
-                panic!("encountered unexpected event kind: expected a Paused event")
-            }
-            let expected_topics = vec![
-                encoded_into_hash(&PrefixedValue {
-                    value: b"InfinityAsset::Paused",
-                    prefix: b"",
-                }),
-                encoded_into_hash(&PrefixedValue {
-                    prefix: b"InfinityAsset::Paused::id",
-                    value: &expected_id,
-                }),
-            ];
-            assert_event_topics(expected_topics, event.topics.clone());
+        /// Get CO2 Emissions recorded for an Asset after it has been blasted,
+        /// i.e. added by transfers and `add_emissions`.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn post_creation_emissions(&self, id: AssetId) -> Option<Vec<CO2Emissions>> {
+            let emissions = self.get_asset_emissions(id)?;
+            let created = self.creation_emissions_count.get(id).unwrap_or(0) as usize;
+            Some(emissions.into_iter().skip(created).collect())
         }
 
-        fn assert_emissions_event(
-            event: &test::EmittedEvent,
-            expected_id: AssetId,
-            expected_category: EmissionsCategory,
-            expected_data_source: DataSource,
-            expected_balanced: bool,
-            expected_date: u64,
-            expected_value: u128,
-        ) {
-            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
-                .expect("Encountered invalid contract event data buffer");
-            if let Event::Emission(Emission {
-                id,
-                category,
-                data_source,
-                balanced,
-                date,
-                value,
-            }) = decoded_event
-            {
-                assert_eq!(id, expected_id, "encountered invalid Emission.id");
-                assert_eq!(
-                    category, expected_category,
-                    "encountered invalid Emission.category"
-                );
-                assert_eq!(
-                    data_source, expected_data_source,
-                    "encountered invalid Emission.data_source"
-                );
-                assert_eq!(
-                    balanced, expected_balanced,
-                    "encountered invalid Emission.balanced"
-                );
-                assert_eq!(date, expected_date, "encountered invalid Emission.date");
-                assert_eq!(value, expected_value, "encountered invalid Emission.value");
-            } else {
-                panic!("encountered unexpected event kind: expected an Emission event")
+        /// Get the CO2 Emissions item with the largest value across an Asset and all its ancestors.
+        /// Ties are broken by the earliest date, then by the item closest to the queried Asset.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn max_emission_in_lineage(&self, id: AssetId) -> Option<CO2Emissions> {
+            self.ensure_recorded(&id).ok()?;
+            self.lineage_emissions(id).into_iter().reduce(|max, item| {
+                if item.value > max.value || (item.value == max.value && item.date < max.date) {
+                    item
+                } else {
+                    max
+                }
+            })
+        }
+
+        /// Find the owners of several Assets at once.
+        ///
+        /// Results are returned in the order of `ids`, with None for Assets that do not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `ids` - The identifiers of the Assets.
+        ///
+        /// # Errors
+        ///
+        /// * `BatchTooLarge` - When more than `MAX_LIST_RETURN` ids are given.
+        ///
+        #[ink(message)]
+        pub fn owner_of_batch(
+            &self,
+            ids: Vec<AssetId>,
+        ) -> Result<Vec<Option<AccountId>>, AssetCO2EmissionsError> {
+            if ids.len() > MAX_LIST_RETURN as usize {
+                return Err(AssetCO2EmissionsError::BatchTooLarge);
             }
-            let expected_topics = vec![
-                encoded_into_hash(&PrefixedValue {
-                    value: b"InfinityAsset::Emission",
-                    prefix: b"",
-                }),
-                encoded_into_hash(&PrefixedValue {
-                    prefix: b"InfinityAsset::Emission::id",
-                    value: &expected_id,
-                }),
-            ];
-            assert_event_topics(expected_topics, event.topics.clone());
+
+            Ok(ids.into_iter().map(|id| self.asset_owner.get(id)).collect())
         }
 
-        fn assert_transfer_event(
-            event: &test::EmittedEvent,
-            expected_id: AssetId,
-            expected_from: AccountId,
-            expected_to: AccountId,
-        ) {
-            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
-                .expect("Encountered invalid contract event data buffer");
-            if let Event::Transfer(Transfer { id, from, to }) = decoded_event {
-                assert_eq!(id, expected_id, "encountered invalid Transfer.id");
-                assert_eq!(from, expected_from, "encountered invalid Transfer.from");
-                assert_eq!(to, expected_to, "encountered invalid Transfer.to");
-            } else {
-                panic!("encountered unexpected event kind: expected a Transfer event")
+        /// Sets classification tags of an Asset (e.g. product type), replacing previous ones.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        /// * `tags` - The tags as bytes, at most `MAX_TAGS_LENGTH` long.
+        ///
+        /// # Errors
+        ///
+        /// * `AssetNotFound` - When the Asset does not exist.
+        /// * `NotOwner` - When transaction sender is not an owner.
+        /// * `TagsOverflow` - When tags exceed maximum length.
+        ///
+        /// # Events
+        ///
+        /// * `AssetTagged` - When Asset tags get set.
+        ///
+        #[ink(message)]
+        pub fn set_asset_tags(
+            &mut self,
+            id: AssetId,
+            tags: Vec<u8>,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_owner(&id, &self.env().caller())?;
+            if tags.len() > MAX_TAGS_LENGTH as usize {
+                return Err(AssetCO2EmissionsError::TagsOverflow);
             }
-            let expected_topics = vec![
-                encoded_into_hash(&PrefixedValue {
-                    value: b"InfinityAsset::Transfer",
-                    prefix: b"",
-                }),
-                encoded_into_hash(&PrefixedValue {
-                    prefix: b"InfinityAsset::Transfer::id",
-                    value: &expected_id,
-                }),
-            ];
-            assert_event_topics(expected_topics, event.topics.clone());
+
+            self.asset_tags.insert(id, &tags);
+            self.env().emit_event(AssetTagged { id, tags });
+            Ok(())
         }
 
-        fn assert_event_topics(expected: Vec<Hash>, topics: Vec<Vec<u8>>) {
-            for (n, (actual_topic, expected_topic)) in topics.iter().zip(expected).enumerate() {
-                let mut topic_hash = Hash::CLEAR_HASH;
-                let len = actual_topic.len();
-                topic_hash.as_mut()[0..len].copy_from_slice(&actual_topic[0..len]);
+        /// Get classification tags of an Asset.
+        ///
+        /// Returns None if Asset does not exist or has never been tagged.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn get_asset_tags(&self, id: AssetId) -> Option<Vec<u8>> {
+            self.asset_tags.get(id)
+        }
 
-                assert_eq!(
-                    topic_hash, expected_topic,
-                    "encountered invalid topic at {n}"
-                );
+        /// List Assets whose classification tags contain the given bytes.
+        ///
+        /// Matching is by substring: an Asset matches if `tag` occurs anywhere in its tags,
+        /// so an empty `tag` matches every tagged Asset. Untagged Assets never match.
+        /// The query scans sequentially assigned Asset ids, so it is paginated:
+        /// the first `skip` ids are skipped and at most `limit` ids are examined.
+        /// `limit` is capped at `MAX_SCAN_LENGTH`.
+        ///
+        /// # Arguments
+        ///
+        /// * `tag` - Bytes to look for in the Asset's tags.
+        /// * `skip` - Number of Asset ids to skip.
+        /// * `limit` - Number of Asset ids to examine.
+        ///
+        #[ink(message)]
+        pub fn assets_by_tag(&self, tag: Vec<u8>, skip: u32, limit: u32) -> Vec<AssetId> {
+            self.scan_ids(skip.into(), limit)
+                .filter(|id| {
+                    self.asset_tags.get(id).is_some_and(|tags| {
+                        tag.is_empty() || tags.windows(tag.len()).any(|window| window == tag)
+                    })
+                })
+                .collect()
+        }
+
+        /// Get an Asset's own CO2 Emissions sorted by date, oldest first.
+        /// Items with the same date keep their insertion order.
+        /// Storage is not modified: `get_asset_emissions` still returns items in insertion order.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn get_asset_emissions_sorted(&self, id: AssetId) -> Option<Vec<CO2Emissions>> {
+            let mut emissions = self.get_asset_emissions(id)?;
+            emissions.sort_by_key(|item| item.date);
+            Some(emissions)
+        }
+
+        /// Permanently disables minting of new Assets. This cannot be undone.
+        /// Existing Assets can still be transferred, paused and queried.
+        /// Must be called by current contract owner.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn seal_contract(&mut self) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            self.minting_sealed = true;
+            Ok(())
+        }
+
+        /// Check if minting of new Assets has been permanently disabled.
+        #[ink(message)]
+        pub fn is_minting_sealed(&self) -> bool {
+            self.minting_sealed
+        }
+
+        /// Get each category's share of the CO2 Emissions of an Asset and all its ancestors, in basis points.
+        ///
+        /// Only categories present in the tree are returned, ordered by category.
+        /// Shares are rounded down, so they sum to 10000 minus at most one basis point per returned category.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn category_percentages(&self, id: AssetId) -> Option<Vec<(EmissionsCategory, u32)>> {
+            self.ensure_recorded(&id).ok()?;
+            let emissions = self.lineage_emissions(id);
+
+            let mut totals = Vec::new();
+            for category in [
+                EmissionsCategory::Process,
+                EmissionsCategory::Transport,
+                EmissionsCategory::Upstream,
+            ] {
+                let total = emissions
+                    .iter()
+                    .filter(|item| item.category == category)
+                    .fold(0u128, |total, item| total.saturating_add(item.value));
+                // CO2 Emissions items are never Zero, so a Zero total means the category is not present.
+                if total > 0 {
+                    totals.push((category, total));
+                }
+            }
+            let lineage_total = totals
+                .iter()
+                .fold(0u128, |total, (_, value)| total.saturating_add(*value));
+
+            Some(
+                totals
+                    .into_iter()
+                    .map(|(category, total)| {
+                        let share = match total.checked_mul(10_000) {
+                            Some(scaled) => scaled / lineage_total,
+                            // Avoid overflow for huge totals at the cost of precision.
+                            None => total / (lineage_total / 10_000),
+                        };
+                        (category, share as u32)
+                    })
+                    .collect(),
+            )
+        }
+
+        /// List an owner's Assets with ids within `[from, to]` (inclusive), sorted ascending.
+        ///
+        /// Returns empty vector if an account does not own any Assets in the range or `from` is greater than `to`.
+        /// At most `MAX_LIST_RETURN` Assets with the lowest ids are returned.
+        ///
+        /// # Arguments
+        ///
+        /// * `owner` - An account for whom to query assets.
+        /// * `from` - The lowest Asset id in the range.
+        /// * `to` - The highest Asset id in the range.
+        ///
+        #[ink(message)]
+        pub fn owned_assets_range(
+            &self,
+            owner: AccountId,
+            from: AssetId,
+            to: AssetId,
+        ) -> Vec<AssetId> {
+            if from > to {
+                return Vec::new();
+            }
+            match self.owned_assets.get(&owner) {
+                None => Vec::new(),
+                Some(owned_assets) => owned_assets
+                    .range(from..=to)
+                    .take(MAX_LIST_RETURN.into())
+                    .copied()
+                    .collect(),
+            }
+        }
+
+        /// Find Assets whose parent does not exist (e.g. after the parent has been force-removed).
+        ///
+        /// # Arguments
+        ///
+        /// * `start` - The first Asset id to check.
+        /// * `limit` - Number of consecutive ids to check, capped at `MAX_SCAN_LENGTH`.
+        ///
+        #[ink(message)]
+        pub fn find_orphans(&self, start: AssetId, limit: u32) -> Vec<AssetId> {
+            let end = start.saturating_add(limit.min(MAX_SCAN_LENGTH).into());
+            (start..end)
+                .filter(|id| self.asset_owner.contains(id))
+                .filter(|id| {
+                    self.parent.get(id).flatten().is_some_and(|(parent_id, _)| {
+                        // Retired parents keep their history, so their children are not orphans.
+                        !self.asset_owner.contains(parent_id) && !self.retired.contains(parent_id)
+                    })
+                })
+                .collect()
+        }
+
+        /// Get the part of CO2 Emissions of an Asset and all its ancestors that is not offset.
+        ///
+        /// Each item contributes `value - offset_value`, so balanced (fully offset) items contribute nothing.
+        /// The total saturates at `u128::MAX`.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn net_unoffset_total(&self, id: AssetId) -> Option<u128> {
+            self.ensure_recorded(&id).ok()?;
+            Some(
+                self.lineage_emissions(id)
+                    .iter()
+                    .filter(|item| !item.balanced)
+                    .fold(0u128, |total, item| {
+                        total.saturating_add(item.value.saturating_sub(item.offset_value))
+                    }),
+            )
+        }
+
+        /// Moves a CO2 Emissions item recorded on the wrong Asset to another Asset.
+        /// The item is removed from the source Asset and appended to the destination Asset's CO2 Emissions.
+        /// Both Assets must be owned by the caller and not paused.
+        ///
+        /// # Arguments
+        ///
+        /// * `from` - The Asset the item is recorded on.
+        /// * `index` - Index of the item in the source Asset's CO2 Emissions.
+        /// * `to` - The Asset the item should be recorded on.
+        ///
+        /// # Errors
+        ///
+        /// * `AlreadyPaused` - When any of the Assets is paused.
+        /// * `AssetNotFound` - When any of the Assets does not exist.
+        /// * `EmissionNotFound` - When the source Asset has no item at `index`.
+        /// * `EmissionsEmpty` - When the item is the only CO2 Emissions item of the source Asset.
+        /// * `EmissionsOverflow` - When the destination Asset's CO2 Emissions would exceed maximum length.
+        /// * `NotOwner` - When transaction sender is not an owner of any of the Assets.
+        ///
+        /// # Events
+        ///
+        /// * `EmissionMoved` - When the item gets moved.
+        ///
+        #[ink(message)]
+        pub fn move_emission(
+            &mut self,
+            from: AssetId,
+            index: u32,
+            to: AssetId,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            let caller = self.env().caller();
+            self.ensure_owner(&from, &caller)?;
+            self.ensure_owner(&to, &caller)?;
+            self.ensure_not_paused(&from)?;
+            self.ensure_not_paused(&to)?;
+
+            let mut from_emissions = self.co2_emissions.get(from).unwrap_or_default();
+            if index as usize >= from_emissions.len() {
+                return Err(AssetCO2EmissionsError::EmissionNotFound);
+            }
+            // An Asset must keep at least one CO2 Emissions item.
+            if from != to && from_emissions.len() == 1 {
+                return Err(AssetCO2EmissionsError::EmissionsEmpty);
+            }
+            // Re-validate the cap of the destination Asset before any storage write.
+            let to_len = match from == to {
+                true => from_emissions.len() - 1,
+                false => self.co2_emissions.get(to).unwrap_or_default().len(),
+            };
+            if to_len >= MAX_EMISSIONS_PER_ASSET as usize {
+                return Err(AssetCO2EmissionsError::EmissionsOverflow);
+            }
+
+            let emission = from_emissions.remove(index as usize);
+            self.co2_emissions.insert(from, &from_emissions);
+            self.forget_emission_index(from, index);
+
+            let mut to_emissions = self.co2_emissions.get(to).unwrap_or_default();
+            to_emissions.push(emission.clone());
+            self.co2_emissions.insert(to, &to_emissions);
+
+            self.env().emit_event(EmissionMoved {
+                from,
+                to,
+                index,
+                emission,
+            });
+            Ok(())
+        }
+
+        /// Get the total offset (balanced) CO2 Emissions value ever recorded across all Assets.
+        ///
+        /// Each item counts with its `offset_value`, i.e. balanced items with their full value.
+        /// CO2 Emissions that would overflow the total are rejected with `EmissionsTotalOverflow`.
+        #[ink(message)]
+        pub fn total_balanced_emissions(&self) -> u128 {
+            self.total_balanced_emissions
+        }
+
+        /// Get the total CO2 Emissions value ever recorded across all Assets.
+        /// The total saturates at `u128::MAX`.
+        #[ink(message)]
+        pub fn total_recorded_emissions(&self) -> u128 {
+            self.total_recorded_emissions
+        }
+
+        /// Freeze the current `total_recorded_emissions` together with the block timestamp
+        /// as an immutable period-end figure. Returns the snapshot index.
+        /// Must be called by current contract owner.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        /// * `SnapshotsOverflow` - When the maximum number of snapshots has been taken.
+        ///
+        #[ink(message)]
+        pub fn take_snapshot(&mut self) -> Result<u32, AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+
+            let index = self.snapshot_count;
+            self.snapshot_count = index
+                .checked_add(1)
+                .ok_or(AssetCO2EmissionsError::SnapshotsOverflow)?;
+            // Block timestamp is in milliseconds, snapshots use seconds like CO2 Emissions dates.
+            let now = self.env().block_timestamp() / 1000;
+            self.snapshots
+                .insert(index, &(now, self.total_recorded_emissions));
+            Ok(index)
+        }
+
+        /// Get a snapshot as (block timestamp in seconds, total recorded CO2 Emissions).
+        /// Returns None if snapshot does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `index` - The snapshot index returned by `take_snapshot`.
+        ///
+        #[ink(message)]
+        pub fn get_snapshot(&self, index: u32) -> Option<(u64, u128)> {
+            self.snapshots.get(index)
+        }
+
+        /// Get the number of Assets ever blasted, including retired ones.
+        /// Assets blasted with deterministic ids are counted as well.
+        #[ink(message)]
+        pub fn total_assets(&self) -> AssetId {
+            self.total_assets
+        }
+
+        /// Get the number of Assets currently live, i.e. blasted and not retired.
+        #[ink(message)]
+        pub fn active_assets(&self) -> u128 {
+            self.active_assets
+        }
+
+        /// Get the CO2 Emission item added most recently to an Asset.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn latest_emission(&self, id: AssetId) -> Option<CO2Emissions> {
+            self.co2_emissions.get(id)?.pop()
+        }
+
+        /// List a page of Assets assigned to an owner, sorted ascending by id.
+        /// The next page starts right after the last id of the previous one.
+        ///
+        /// Returns empty vector if an account does not own any Assets with ids from `start`.
+        ///
+        /// # Arguments
+        ///
+        /// * `owner` - An account for whom to query assets.
+        /// * `start` - The lowest Asset id of the page.
+        /// * `limit` - Maximum number of Assets to return, capped at `MAX_LIST_RETURN`.
+        ///
+        #[ink(message)]
+        pub fn list_assets_paged(
+            &self,
+            owner: AccountId,
+            start: AssetId,
+            limit: u32,
+        ) -> Vec<AssetId> {
+            match self.owned_assets.get(&owner) {
+                None => Vec::new(),
+                Some(owned_assets) => owned_assets
+                    .range(start..)
+                    .take(limit.min(MAX_LIST_RETURN.into()) as usize)
+                    .copied()
+                    .collect(),
+            }
+        }
+
+        /// Get the total CO2 Emissions across an Asset's full provenance tree (the Asset and all its ancestors),
+        /// i.e. the carbon footprint of a finished product in one call.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        /// # Errors
+        ///
+        /// * `EmissionsTotalOverflow` - When the total exceeds `u128::MAX`.
+        ///
+        #[ink(message)]
+        pub fn total_tree_emissions(
+            &self,
+            id: AssetId,
+        ) -> Result<Option<u128>, AssetCO2EmissionsError> {
+            let Some(tree) = self.query_emissions(id) else {
+                return Ok(None);
+            };
+            tree.iter()
+                .flat_map(|asset| asset.emissions.iter())
+                .try_fold(0u128, |total, item| total.checked_add(item.value))
+                .map(Some)
+                .ok_or(AssetCO2EmissionsError::EmissionsTotalOverflow)
+        }
+
+        /// Get how many distinct CO2 Emissions categories appear across an Asset and all its ancestors.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn category_count_in_lineage(&self, id: AssetId) -> Option<u32> {
+            self.ensure_recorded(&id).ok()?;
+            let mut categories: Vec<EmissionsCategory> = Vec::new();
+            for item in self.lineage_emissions(id) {
+                if !categories.contains(&item.category) {
+                    categories.push(item.category);
+                }
+            }
+            Some(categories.len() as u32)
+        }
+
+        /// Get the own CO2 Emissions total of each generation in an Asset's lineage,
+        /// indexed by generation: 0 is the root, the last item is the Asset itself.
+        /// Totals saturate at `u128::MAX`.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn emissions_per_generation(&self, id: AssetId) -> Option<Vec<u128>> {
+            self.ensure_recorded(&id).ok()?;
+            Some(
+                self.lineage(id)
+                    .into_iter()
+                    .rev()
+                    .map(|asset_id| self.own_emissions_total(asset_id))
+                    .collect(),
+            )
+        }
+
+        /// Get an Asset's own CO2 Emissions dated within `[from, to]` (inclusive).
+        /// The recorded `date` of each item (UNIX timestamp) is used, not the block time of recording.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        /// * `from` - Start of the period, UNIX timestamp.
+        /// * `to` - End of the period, UNIX timestamp.
+        ///
+        #[ink(message)]
+        pub fn get_emissions_in_range(
+            &self,
+            id: AssetId,
+            from: u64,
+            to: u64,
+        ) -> Option<Vec<CO2Emissions>> {
+            Some(
+                self.get_asset_emissions(id)?
+                    .into_iter()
+                    .filter(|item| (from..=to).contains(&item.date))
+                    .collect(),
+            )
+        }
+
+        /// Registers or unregisters a measurement methodology id CO2 Emissions can be recorded against.
+        /// Methodology 0 (unspecified) is always accepted.
+        /// Must be called by current contract owner.
+        ///
+        /// # Arguments
+        ///
+        /// * `methodology` - The methodology id.
+        /// * `registered` - Whether the methodology is accepted for new CO2 Emissions.
+        ///
+        /// # Errors
+        ///
+        /// * `NotContractOwner` - When action triggered by not the current owner.
+        ///
+        #[ink(message)]
+        pub fn set_methodology(
+            &mut self,
+            methodology: u16,
+            registered: bool,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_contract_owner(self.env().caller())?;
+            if registered {
+                self.methodologies.insert(methodology, &true);
+            } else {
+                self.methodologies.remove(methodology);
+            }
+            Ok(())
+        }
+
+        /// Get whether CO2 Emissions can be recorded against a measurement methodology id.
+        ///
+        /// # Arguments
+        ///
+        /// * `methodology` - The methodology id.
+        ///
+        #[ink(message)]
+        pub fn is_methodology_registered(&self, methodology: u16) -> bool {
+            methodology == 0 || self.methodologies.contains(methodology)
+        }
+
+        /// Get an Asset's own CO2 Emissions recorded against a measurement methodology.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        /// * `methodology` - The methodology id, 0 for records with unspecified methodology.
+        ///
+        #[ink(message)]
+        pub fn emissions_by_methodology(
+            &self,
+            id: AssetId,
+            methodology: u16,
+        ) -> Option<Vec<CO2Emissions>> {
+            Some(
+                self.get_asset_emissions(id)?
+                    .into_iter()
+                    .filter(|item| item.methodology == methodology)
+                    .collect(),
+            )
+        }
+
+        /// Get the child Assets split from an Asset, in blasting order.
+        ///
+        /// Returns None if Asset does not exist, an empty vector if it has no children.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn get_children(&self, id: AssetId) -> Option<Vec<AssetId>> {
+            self.ensure_recorded(&id).ok()?;
+            Some(self.children.get(id).unwrap_or_default())
+        }
+
+        /// Get the ids of an Asset and all its ancestors, starting with the queried Asset itself
+        /// and ending with the root, in the same order as `query_emissions`.
+        /// Only parent links are followed, so it is much cheaper than `query_emissions`.
+        ///
+        /// Returns None if Asset does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn ancestor_ids(&self, id: AssetId) -> Option<Vec<AssetId>> {
+            self.ensure_recorded(&id).ok()?;
+            Some(self.lineage(id))
+        }
+
+        /// Check that following parent links from an Asset never revisits an Asset,
+        /// i.e. its provenance tree contains no cycle.
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - The Asset id.
+        ///
+        #[ink(message)]
+        pub fn validate_tree_acyclic(&self, id: AssetId) -> bool {
+            let mut visited = BTreeSet::new();
+            let mut next = Some(id);
+            while let Some(asset_id) = next {
+                if !visited.insert(asset_id) {
+                    return false;
+                }
+                next = self
+                    .parent
+                    .get(asset_id)
+                    .flatten()
+                    .map(|(parent_id, _)| parent_id);
+            }
+            true
+        }
+
+        /// Get CO2 Emissions statistics across an owner's whole portfolio.
+        /// Only own CO2 Emissions of the owned Assets are counted, ancestors are not traversed.
+        /// Totals saturate at `u128::MAX`.
+        ///
+        /// # Arguments
+        ///
+        /// * `owner` - An account for whom to aggregate the statistics.
+        ///
+        #[ink(message)]
+        pub fn portfolio_stats(&self, owner: AccountId) -> EmissionStats {
+            let Some(owned_assets) = self.owned_assets.get(&owner) else {
+                return EmissionStats::default();
+            };
+            owned_assets
+                .iter()
+                .flat_map(|id| self.co2_emissions.get(id).unwrap_or_default())
+                .fold(EmissionStats::default(), |stats, item| EmissionStats {
+                    count: stats.count.saturating_add(1),
+                    total: stats.total.saturating_add(item.value),
+                    balanced: stats.balanced.saturating_add(match item.balanced {
+                        true => item.value,
+                        false => item.offset_value,
+                    }),
+                })
+        }
+
+        /// Insert new Asset in the Assets of `owner`.
+        fn insert_owned_asset(
+            &mut self,
+            owner: &AccountId,
+            asset_id: &AssetId,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            match self.owned_assets.get_mut(owner) {
+                None => {
+                    let mut new_owned_assets = BTreeSet::new();
+                    new_owned_assets.insert(*asset_id);
+                    self.owned_assets.insert(*owner, new_owned_assets);
+                }
+                Some(owned_assets) => {
+                    owned_assets.insert(*asset_id);
+                }
+            }
+            Ok(())
+        }
+
+        /// Remove Asset from the Assets of `owner`.
+        fn remove_owned_asset(
+            &mut self,
+            owner: &AccountId,
+            asset_id: &AssetId,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.owned_assets
+                .get_mut(owner)
+                .expect("Owned assets must exist when removing during Asset transfer")
+                .remove(asset_id);
+            Ok(())
+        }
+
+        /// Ensure that Asset does not exist.
+        fn ensure_not_exist(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
+            // Retired Assets keep their id forever, so it can never be minted again.
+            match self.asset_owner.contains(id) || self.retired.contains(id) {
+                false => Ok(()),
+                true => Err(AssetCO2EmissionsError::AssetAlreadyExists),
+            }
+        }
+
+        /// Ensure that Asset does exist and is not retired.
+        fn ensure_exists(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
+            match self.asset_owner.contains(id) {
+                true => Ok(()),
+                false => Err(self.missing_asset_error(id)),
+            }
+        }
+
+        /// Ensure that Asset has been recorded, i.e. it exists or has been retired with its history kept.
+        fn ensure_recorded(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
+            match self.asset_owner.contains(id) || self.retired.contains(id) {
+                true => Ok(()),
+                false => Err(AssetCO2EmissionsError::AssetNotFound),
+            }
+        }
+
+        /// Error for an Asset without an owner: retired or never blasted.
+        fn missing_asset_error(&self, id: &AssetId) -> AssetCO2EmissionsError {
+            match self.retired.contains(id) {
+                true => AssetCO2EmissionsError::AlreadyRetired,
+                false => AssetCO2EmissionsError::AssetNotFound,
+            }
+        }
+
+        /// Ensure the calling origin is the contract owner.
+        fn ensure_contract_owner(&self, caller: AccountId) -> Result<(), AssetCO2EmissionsError> {
+            match caller.eq(&self.contract_owner) {
+                true => Ok(()),
+                false => Err(AssetCO2EmissionsError::NotContractOwner),
+            }
+        }
+
+        /// Ensure the calling origin is the Asset owner.
+        fn ensure_owner(
+            &self,
+            id: &AssetId,
+            account: &AccountId,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            match self.asset_owner.get(id) {
+                None => Err(self.missing_asset_error(id)),
+                Some(owner) => {
+                    if owner.eq(account) {
+                        Ok(())
+                    } else {
+                        Err(AssetCO2EmissionsError::NotOwner)
+                    }
+                }
+            }
+        }
+
+        /// Ensure the Asset is `Paused`.
+        fn ensure_paused(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
+            match self.has_paused(*id) {
+                None => Err(AssetCO2EmissionsError::AssetNotFound),
+                Some(false) => Err(AssetCO2EmissionsError::NotPaused),
+                Some(true) => Ok(()),
+            }
+        }
+
+        /// Ensure the Asset is not `Paused`.
+        fn ensure_not_paused(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
+            match self.has_paused(*id) {
+                None => Err(AssetCO2EmissionsError::AssetNotFound),
+                Some(true) => Err(AssetCO2EmissionsError::AlreadyPaused),
+                Some(false) => Ok(()),
+            }
+        }
+
+        /// Ensure the parent details of child Asset (to be assigned `child_id`) are correct.
+        fn ensure_proper_parent(
+            &self,
+            parent: &ParentDetails,
+            caller: &AccountId,
+            child_id: AssetId,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            match parent {
+                None => Ok(()),
+                Some((_, 0)) => Err(AssetCO2EmissionsError::InvalidAssetRelation),
+                Some((parent_id, _)) if *parent_id == child_id => {
+                    Err(AssetCO2EmissionsError::SelfParent)
+                }
+                Some((parent_id, relation)) => {
+                    self.ensure_can_be_parent(parent_id, caller)?;
+                    self.ensure_parent_quantity_available(parent_id, *relation)
+                }
+            }
+        }
+
+        /// Ensure the parent Asset has enough undivided quantity left for a child with the given relation.
+        /// Assets without a declared quantity are not limited.
+        fn ensure_parent_quantity_available(
+            &self,
+            id: &AssetId,
+            relation: ParentRelation,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            let Some(quantity) = self.quantity.get(id) else {
+                return Ok(());
+            };
+            match self
+                .split_quantity
+                .get(id)
+                .unwrap_or(0)
+                .checked_add(relation)
+            {
+                Some(split) if split <= quantity => Ok(()),
+                _ => Err(AssetCO2EmissionsError::ParentQuantityExceeded),
+            }
+        }
+
+        /// Depth of an Asset in the provenance tree (number of its ancestors).
+        /// Falls back to walking the parent links for Assets without a stored depth.
+        fn depth_of(&self, id: AssetId) -> u32 {
+            self.depth
+                .get(id)
+                .unwrap_or_else(|| (self.lineage(id).len() as u32).saturating_sub(1))
+        }
+
+        /// Ensure the parent Asset can get `additional` children without exceeding `MAX_CHILDREN_PER_ASSET`.
+        fn ensure_children_available(
+            &self,
+            id: &AssetId,
+            additional: u32,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            let count = self.children.get(id).unwrap_or_default().len() as u32;
+            match count.saturating_add(additional) <= MAX_CHILDREN_PER_ASSET.into() {
+                true => Ok(()),
+                false => Err(AssetCO2EmissionsError::ChildrenOverflow),
+            }
+        }
+
+        /// Ensure the Asset can be used as a parent of a new child Asset by the `account`.
+        fn ensure_can_be_parent(
+            &self,
+            id: &AssetId,
+            account: &AccountId,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_owner(id, account)?;
+            self.ensure_paused(id)?;
+            self.ensure_children_available(id, 1)?;
+            match self.depth_of(*id) < MAX_TREE_DEPTH {
+                true => Ok(()),
+                false => Err(AssetCO2EmissionsError::TreeDepthExceeded),
+            }
+        }
+
+        /// Ensure that CO2 Emissions are correct: not empty, not unbounded, and all items are correct.
+        fn ensure_emissions_correct(
+            &self,
+            asset: Option<AssetId>,
+            emissions: &[CO2Emissions],
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_emissions_not_empty(emissions)?;
+            self.ensure_emissions_not_unbounded(emissions)?;
+            if self.reject_duplicate_emissions {
+                self.ensure_emissions_not_duplicated(emissions)?;
+            }
+
+            match asset {
+                None => (),
+                Some(asset_id) => {
+                    let mut updated_emissions =
+                        self.co2_emissions.get(asset_id).unwrap_or_default();
+                    updated_emissions.extend_from_slice(emissions);
+                    self.ensure_emissions_not_unbounded(&updated_emissions)?;
+                }
+            }
+
+            // Ensure all CO2 Emissions items are correct.
+            emissions.iter().try_for_each(|item| {
+                self.ensure_emissions_item_correct(item)?;
+                Ok(())
+            })?;
+            self.balanced_total_after(emissions).map(|_| ())
+        }
+
+        /// Ensure CO2 Emissions vec is not empty.
+        fn ensure_emissions_not_empty(
+            &self,
+            emissions: &[CO2Emissions],
+        ) -> Result<(), AssetCO2EmissionsError> {
+            match emissions.len() {
+                0 => Err(AssetCO2EmissionsError::EmissionsEmpty),
+                _ => Ok(()),
+            }
+        }
+
+        /// Ensure length of CO2 Emissions vec is not greater than `MAX_EMISSIONS_PER_ASSET`.
+        fn ensure_emissions_not_unbounded(
+            &self,
+            emissions: &[CO2Emissions],
+        ) -> Result<(), AssetCO2EmissionsError> {
+            if emissions.len() > MAX_EMISSIONS_PER_ASSET as usize {
+                return Err(AssetCO2EmissionsError::EmissionsOverflow);
+            }
+            Ok(())
+        }
+
+        /// Ensure CO2 Emissions vec does not contain the same item more than once.
+        fn ensure_emissions_not_duplicated(
+            &self,
+            emissions: &[CO2Emissions],
+        ) -> Result<(), AssetCO2EmissionsError> {
+            match emissions
+                .iter()
+                .enumerate()
+                .any(|(i, item)| emissions[..i].contains(item))
+            {
+                true => Err(AssetCO2EmissionsError::DuplicateEmissionInBatch),
+                false => Ok(()),
+            }
+        }
+
+        /// Ensure length of Data Source for CO2 Emission item is not greater than `MAX_DATA_SOURCE_LENGTH`.
+        fn ensure_emissions_data_src_not_unbounded(
+            &self,
+            data_source: &DataSource,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            if data_source.len() > MAX_DATA_SOURCE_LENGTH as usize {
+                return Err(AssetCO2EmissionsError::DataSourceOverflow);
+            }
+            Ok(())
+        }
+
+        /// Ensure CO2 Emissions item is correct.
+        fn ensure_emissions_item_correct(
+            &self,
+            item: &CO2Emissions,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_emissions_data_src_not_unbounded(&item.data_source)?;
+            if self.require_data_source && item.data_source.is_empty() {
+                return Err(AssetCO2EmissionsError::DataSourceRequired);
+            }
+            self.ensure_emissions_item_not_zero(item)?;
+            if item.offset_value > item.value {
+                return Err(AssetCO2EmissionsError::OffsetExceedsValue);
+            }
+            self.ensure_emissions_item_not_future(item)?;
+            match self.is_methodology_registered(item.methodology) {
+                true => Ok(()),
+                false => Err(AssetCO2EmissionsError::UnknownMethodology),
+            }
+        }
+
+        /// Ensure CO2 Emissions item is not dated after the current block (allowing `FUTURE_DATE_TOLERANCE`).
+        fn ensure_emissions_item_not_future(
+            &self,
+            item: &CO2Emissions,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            // Block timestamp is in milliseconds, CO2 Emissions dates in seconds.
+            let now = self.env().block_timestamp() / 1000;
+            match item.date > now.saturating_add(FUTURE_DATE_TOLERANCE) {
+                true => Err(AssetCO2EmissionsError::FutureEmissionsDate),
+                false => Ok(()),
+            }
+        }
+
+        /// Ensure CO2 Emissions item value is non-zero.
+        fn ensure_emissions_item_not_zero(
+            &self,
+            emissions: &CO2Emissions,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            match emissions.value {
+                0 => Err(AssetCO2EmissionsError::ZeroEmissionsItem),
+                _ => Ok(()),
+            }
+        }
+
+        /// Ensure metadata does not exceed `MAX_METADATA_LENGTH`.
+        fn ensure_proper_metadata(
+            &self,
+            metadata: &Metadata,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            if metadata.len() > MAX_METADATA_LENGTH as usize {
+                return Err(AssetCO2EmissionsError::MetadataOverflow);
+            }
+            Ok(())
+        }
+
+        /// Contract-wide total of balanced CO2 Emissions after recording `emissions`.
+        fn balanced_total_after(
+            &self,
+            emissions: &[CO2Emissions],
+        ) -> Result<u128, AssetCO2EmissionsError> {
+            // count offset parts of the normalized items, i.e. the full value of balanced ones.
+            emissions
+                .iter()
+                .cloned()
+                .map(Self::normalize_offset)
+                .try_fold(self.total_balanced_emissions, |total, item| {
+                    total.checked_add(item.offset_value)
+                })
+                .ok_or(AssetCO2EmissionsError::EmissionsTotalOverflow)
+        }
+
+        /// Normalize the offset of a CO2 Emissions item, so `balanced` always equals `offset_value == value`.
+        /// An item marked as balanced without a recorded offset is fully offset.
+        fn normalize_offset(mut item: CO2Emissions) -> CO2Emissions {
+            if item.balanced && item.offset_value == 0 {
+                item.offset_value = item.value;
+            }
+            item.balanced = item.offset_value == item.value;
+            item
+        }
+
+        /// Save new CO2 Emissions for Asset and emit an event for each emission item.
+        fn save_new_co2_emissions(
+            &mut self,
+            id: &AssetId,
+            emissions: &[CO2Emissions],
+        ) -> Result<(), AssetCO2EmissionsError> {
+            let total_balanced_emissions = self.balanced_total_after(emissions)?;
+
+            let emissions: Vec<CO2Emissions> = emissions
+                .iter()
+                .cloned()
+                .map(Self::normalize_offset)
+                .collect();
+
+            let mut updated_emissions = self.co2_emissions.get(id).unwrap_or_default();
+            updated_emissions.extend_from_slice(&emissions);
+
+            self.co2_emissions.insert(id, &updated_emissions);
+
+            self.total_balanced_emissions = total_balanced_emissions;
+
+            let added = emissions
+                .iter()
+                .fold(0u128, |total, item| total.saturating_add(item.value));
+            self.total_recorded_emissions = self.total_recorded_emissions.saturating_add(added);
+
+            // credit new CO2 Emissions to the current Asset owner.
+            if let Some(owner) = self.asset_owner.get(id) {
+                self.credit_owner_emissions(&owner, added);
+            }
+
+            // emit an event for each emission item.
+            emissions.iter().for_each(|emission| {
+                self.env().emit_event(Emission {
+                    id: *id,
+                    category: emission.category,
+                    data_source: emission.data_source.clone(),
+                    balanced: emission.balanced,
+                    date: emission.date,
+                    value: emission.value,
+                    evidence_hash: emission.evidence_hash,
+                })
+            });
+
+            // pause the Asset for review when this call makes it reach the cap.
+            let cap = usize::from(MAX_EMISSIONS_PER_ASSET);
+            let reached_cap =
+                updated_emissions.len() >= cap && updated_emissions.len() - emissions.len() < cap;
+            if self.auto_pause_on_cap && reached_cap && self.paused.get(id) == Some(false) {
+                self.paused.insert(id, &true);
+                self.env().emit_event(Paused { id: *id });
+            }
+
+            Ok(())
+        }
+
+        /// Return the next id and increase by 1.
+        /// Refuses to assign an id below the persisted high-water mark, so ids are never reused.
+        fn next_id(&mut self) -> Result<AssetId, AssetCO2EmissionsError> {
+            if self.next_id < self.id_high_water {
+                return Err(AssetCO2EmissionsError::IdRegression);
+            }
+            let asset_id = self.next_id;
+            self.next_id = self
+                .next_id
+                .checked_add(1)
+                .ok_or(AssetCO2EmissionsError::AssetIdOverflow)?;
+            self.id_high_water = self.next_id;
+            Ok(asset_id)
+        }
+
+        /// Range of assigned Asset ids after skipping `skip` ids, at most `limit` (capped at `MAX_SCAN_LENGTH`) long.
+        fn scan_ids(&self, skip: AssetId, limit: u32) -> core::ops::Range<AssetId> {
+            let start = skip.saturating_add(1);
+            let end = start
+                .saturating_add(limit.min(MAX_SCAN_LENGTH).into())
+                .min(self.next_id);
+            start..end.max(start)
+        }
+
+        /// Transfer an Asset from `from` to `to` recording CO2 Emissions caused by the transfer.
+        fn transfer_asset(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            id: AssetId,
+            emissions: Vec<CO2Emissions>,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            // All validations (including the CO2 Emissions cap) happen before any storage write.
+            self.ensure_not_retired(&id)?;
+            self.ensure_exists(&id)?;
+            self.ensure_owner(&id, &from)?;
+            if from == to {
+                return Err(AssetCO2EmissionsError::TransferToSelf);
+            }
+            self.ensure_not_paused(&id)?;
+            self.ensure_emissions_correct(Some(id), &emissions)?;
+
+            self.remove_owned_asset(&from, &id)?;
+            self.insert_owned_asset(&to, &id)?;
+            // Any ownership change invalidates a transfer awaiting acknowledgment.
+            self.pending_transfers.remove(id);
+
+            self.asset_owner.insert(id, &to);
+            // Only the Asset's own CO2 Emissions move with it, inherited ones stay with ancestors' owners.
+            let own = self.own_emissions_total(id);
+            self.owner_emissions.insert(
+                from,
+                &self
+                    .owner_emissions
+                    .get(from)
+                    .unwrap_or(0)
+                    .saturating_sub(own),
+            );
+            self.credit_owner_emissions(&to, own);
+            self.transfer_count.insert(
+                id,
+                &self.transfer_count.get(id).unwrap_or(0).saturating_add(1),
+            );
+
+            self.env().emit_event(Transfer { from, to, id });
+
+            let start = self.co2_emissions.get(id).unwrap_or_default().len() as u32;
+            self.last_transfer_emissions
+                .insert(id, &(start, emissions.len() as u32));
+
+            // Save CO2 Emissions & emit corresponding events.
+            self.save_new_co2_emissions(&id, &emissions)?;
+
+            // Any ownership change cancels an outstanding offer, so it cannot be claimed later.
+            // An offer taken up by its recipient is consumed rather than revoked.
+            if let Some(offered) = self.offers.get(id) {
+                self.offers.remove(id);
+                if offered != to {
+                    self.env().emit_event(OfferRevoked { id });
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Convert a UNIX timestamp (in seconds) into its Gregorian calendar year.
+        fn year_of(date: u64) -> u32 {
+            // Days are counted from 0000-03-01 in 400-year eras of 146097 days,
+            // so the leap day is the last day of a year (Howard Hinnant's `civil_from_days`).
+            let days = date / 86_400 + 719_468;
+            let era = days / 146_097;
+            let day_of_era = days % 146_097;
+            let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524
+                - day_of_era / 146_096)
+                / 365;
+            let day_of_year =
+                day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+            // Months are counted from March, so January and February belong to the next year.
+            let month = (5 * day_of_year + 2) / 153;
+            let year = era * 400 + year_of_era + u64::from(month >= 10);
+            u32::try_from(year).unwrap_or(u32::MAX)
+        }
+
+        /// Collect Asset ids from `id` up to the root following parent links.
+        /// Stops if an id repeats, so corrupted cyclic links cannot loop forever.
+        fn lineage(&self, id: AssetId) -> Vec<AssetId> {
+            let mut visited = BTreeSet::new();
+            let mut lineage = Vec::new();
+            let mut next = Some(id);
+            while let Some(asset_id) = next {
+                if !visited.insert(asset_id) {
+                    break;
+                }
+                lineage.push(asset_id);
+                next = self
+                    .parent
+                    .get(asset_id)
+                    .flatten()
+                    .map(|(parent_id, _)| parent_id);
+            }
+            lineage
+        }
+
+        /// Collect CO2 Emissions of an Asset and all its ancestors.
+        fn lineage_emissions(&self, id: AssetId) -> Vec<CO2Emissions> {
+            self.lineage(id)
+                .into_iter()
+                .flat_map(|asset_id| self.co2_emissions.get(asset_id).unwrap_or_default())
+                .collect()
+        }
+
+        /// Sum of CO2 Emissions values of the Asset itself.
+        fn own_emissions_total(&self, id: AssetId) -> u128 {
+            self.co2_emissions
+                .get(id)
+                .unwrap_or_default()
+                .iter()
+                .fold(0, |total, item| total.saturating_add(item.value))
+        }
+
+        /// Sum of CO2 Emissions values of the Asset and all its ancestors.
+        fn total_emissions(&self, id: AssetId) -> u128 {
+            self.lineage(id).into_iter().fold(0, |total, asset_id| {
+                total.saturating_add(self.own_emissions_total(asset_id))
+            })
+        }
+
+        /// Count a CO2 Emissions item against the Asset's rate limit window.
+        /// The window restarts once `window_seconds` have passed since its start.
+        fn consume_emission_rate(&mut self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
+            if self.max_emissions_per_window == 0 {
+                return Ok(());
+            }
+
+            // Block timestamp is in milliseconds.
+            let now = self.env().block_timestamp() / 1000;
+            let (start, count) = match self.emission_window.get(id) {
+                Some((start, count)) if now < start.saturating_add(self.window_seconds) => {
+                    (start, count)
+                }
+                _ => (now, 0),
+            };
+            if count >= self.max_emissions_per_window {
+                return Err(AssetCO2EmissionsError::EmissionRateLimited);
+            }
+
+            self.emission_window.insert(id, &(start, count + 1));
+            Ok(())
+        }
+
+        /// Insert a new Asset with all its details & emit corresponding events.
+        /// All validations must be done by the caller.
+        fn mint_asset(
+            &mut self,
+            asset_id: AssetId,
+            to: AccountId,
+            metadata: Metadata,
+            emissions: Vec<CO2Emissions>,
+            parent: ParentDetails,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.insert_owned_asset(&to, &asset_id)?;
+
+            self.asset_owner.insert(asset_id, &to);
+            self.metadata.insert(asset_id, &metadata);
+            self.paused.insert(asset_id, &false);
+            self.parent.insert(asset_id, &parent);
+            if let Some((parent_id, relation)) = parent {
+                self.depth
+                    .insert(asset_id, &self.depth_of(parent_id).saturating_add(1));
+                let mut children = self.children.get(parent_id).unwrap_or_default();
+                children.push(asset_id);
+                self.children.insert(parent_id, &children);
+                self.quantity.insert(asset_id, &relation);
+                self.split_quantity.insert(
+                    parent_id,
+                    &self
+                        .split_quantity
+                        .get(parent_id)
+                        .unwrap_or(0)
+                        .saturating_add(relation),
+                );
+            }
+
+            self.env().emit_event(Blasted {
+                id: asset_id,
+                metadata,
+                owner: to,
+                parent,
+            });
+
+            // Save CO2 Emissions & emit corresponding events.
+            self.save_new_co2_emissions(&asset_id, &emissions)?;
+            self.creation_emissions_count
+                .insert(asset_id, &(emissions.len() as u32));
+
+            self.total_assets = self.total_assets.saturating_add(1);
+            self.active_assets = self.active_assets.saturating_add(1);
+
+            if self.storage_deposit > 0 {
+                self.deposits.insert(asset_id, &self.storage_deposit);
+                self.held_deposits = self.held_deposits.saturating_add(self.storage_deposit);
+            }
+
+            Ok(())
+        }
+
+        /// Derive an Asset id from the caller and a nonce: the first 16 bytes of `Blake2x256(caller || nonce)`.
+        fn deterministic_id(&self, caller: &AccountId, nonce: u64) -> AssetId {
+            let mut input = scale::Encode::encode(caller);
+            input.extend_from_slice(&nonce.to_le_bytes());
+            let hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&input);
+
+            let mut id = [0u8; 16];
+            id.copy_from_slice(&hash[..16]);
+            AssetId::from_le_bytes(id)
+        }
+
+        /// Add CO2 Emissions to the account's owned Assets total.
+        fn credit_owner_emissions(&mut self, owner: &AccountId, value: u128) {
+            self.owner_emissions.insert(
+                owner,
+                &self
+                    .owner_emissions
+                    .get(owner)
+                    .unwrap_or(0)
+                    .saturating_add(value),
+            );
+        }
+
+        /// Ensure the value transferred with the call covers the blast fee of `assets` Assets.
+        fn ensure_blast_fee_paid(&self, assets: u32) -> Result<(), AssetCO2EmissionsError> {
+            match self.env().transferred_value() >= self.blast_fee.saturating_mul(assets.into()) {
+                true => Ok(()),
+                false => Err(AssetCO2EmissionsError::InsufficientFee),
+            }
+        }
+
+        /// Transfer the blast fee of `assets` Assets to the contract owner; any overpayment stays in the contract.
+        fn pay_blast_fee(&mut self, assets: u32) -> Result<(), AssetCO2EmissionsError> {
+            if self.blast_fee == 0 {
+                return Ok(());
+            }
+            self.env()
+                .transfer(
+                    self.contract_owner,
+                    self.blast_fee.saturating_mul(assets.into()),
+                )
+                .map_err(|_| AssetCO2EmissionsError::TransferFailed)
+        }
+
+        /// Ensure the value transferred with the call covers the blast fee and the storage deposit of `assets` Assets.
+        fn ensure_storage_deposit_paid(&self, assets: u32) -> Result<(), AssetCO2EmissionsError> {
+            let required = self
+                .blast_fee
+                .saturating_add(self.storage_deposit)
+                .saturating_mul(assets.into());
+            match self.env().transferred_value() >= required {
+                true => Ok(()),
+                false => Err(AssetCO2EmissionsError::InsufficientDeposit),
+            }
+        }
+
+        /// Ensure minting of new Assets has not been sealed.
+        fn ensure_minting_not_sealed(&self) -> Result<(), AssetCO2EmissionsError> {
+            match self.minting_sealed {
+                false => Ok(()),
+                true => Err(AssetCO2EmissionsError::MintingSealed),
+            }
+        }
+
+        /// Keep recorded CO2 Emissions ranges of an Asset consistent after the item at `index` is removed.
+        fn forget_emission_index(&mut self, id: AssetId, index: u32) {
+            if let Some(count) = self.creation_emissions_count.get(id) {
+                if index < count {
+                    self.creation_emissions_count.insert(id, &(count - 1));
+                }
+            }
+            if let Some((start, len)) = self.last_transfer_emissions.get(id) {
+                let range = if index < start {
+                    (start - 1, len)
+                } else if index < start.saturating_add(len) {
+                    (start, len - 1)
+                } else {
+                    (start, len)
+                };
+                self.last_transfer_emissions.insert(id, &range);
+            }
+        }
+
+        /// Ensure the Asset is not retired.
+        fn ensure_not_retired(&self, id: &AssetId) -> Result<(), AssetCO2EmissionsError> {
+            match self.retired.contains(id) {
+                false => Ok(()),
+                true => Err(AssetCO2EmissionsError::AlreadyRetired),
+            }
+        }
+
+        /// Build Asset tree from child to parent.
+        fn build_asset_tree(&self, id: AssetId) -> Vec<AssetDetails> {
+            let mut asset_id = id;
+            let mut tree_path: Vec<AssetDetails> = Vec::new();
+            let mut visited = BTreeSet::new();
+            // This function is called after initial check if asset exists,
+            // but a parent may have been removed (e.g. by admin tooling),
+            // leaving an orphaned child -- stop at the missing parent instead of panicking.
+            while let Some(asset) = self.get_asset(asset_id) {
+                // Corrupted cyclic parent links must not loop forever, return the path so far.
+                if !visited.insert(asset_id) {
+                    break;
+                }
+                let parent_details = asset.parent;
+                tree_path.push(asset);
+                match parent_details {
+                    None => break,
+                    Some((parent_id, _)) => asset_id = parent_id,
+                }
+            }
+
+            tree_path
+        }
+    }
+
+    impl AssetCO2Emissions for InfinityAsset {
+        #[ink(message)]
+        fn list_assets(&self, owner: AccountId) -> Vec<AssetId> {
+            self.list_assets_paged(owner, 0, MAX_LIST_RETURN.into())
+        }
+
+        #[ink(message)]
+        fn owner_of(&self, id: AssetId) -> Option<AccountId> {
+            self.asset_owner.get(id)
+        }
+
+        #[ink(message)]
+        fn blast(
+            &mut self,
+            to: AccountId,
+            metadata: Metadata,
+            emissions: Vec<CO2Emissions>,
+            parent: ParentDetails,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            let caller = self.env().caller();
+
+            self.ensure_minting_not_sealed()?;
+            self.ensure_blast_fee_paid(1)?;
+            self.ensure_storage_deposit_paid(1)?;
+            self.ensure_proper_metadata(&metadata)?;
+            self.ensure_emissions_correct(None, &emissions)?;
+            // Peek at the id to be assigned, so a self-referencing parent gets rejected.
+            self.ensure_proper_parent(&parent, &caller, self.next_id)?;
+
+            let asset_id: u128 = self.next_id()?;
+            self.ensure_not_exist(&asset_id)?;
+
+            self.mint_asset(asset_id, to, metadata, emissions, parent)?;
+            self.pay_blast_fee(1)?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            id: AssetId,
+            emissions: Vec<CO2Emissions>,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            let from = self.env().caller();
+            self.transfer_asset(from, to, id, emissions)
+        }
+
+        #[ink(message)]
+        fn pause(&mut self, id: AssetId) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_not_retired(&id)?;
+            self.ensure_owner(&id, &self.env().caller())?;
+            self.ensure_not_paused(&id)?;
+
+            self.paused.insert(id, &true);
+            self.env().emit_event(Paused { id });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn unpause(&mut self, id: AssetId) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_owner(&id, &self.env().caller())?;
+            self.ensure_paused(&id)?;
+            if !self.children.get(id).unwrap_or_default().is_empty() {
+                return Err(AssetCO2EmissionsError::HasChildren);
+            }
+
+            self.paused.insert(id, &false);
+            self.env().emit_event(Unpaused { id });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn retire(
+            &mut self,
+            id: AssetId,
+            emissions: Vec<CO2Emissions>,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            let owner = self.env().caller();
+            self.ensure_not_retired(&id)?;
+            self.ensure_owner(&id, &owner)?;
+            // Children inherit a paused (split) Asset's CO2 Emissions, which must not change afterwards,
+            // so such an Asset gets retired without final CO2 Emissions.
+            match self.paused.get(id) == Some(true) {
+                true if !emissions.is_empty() => return Err(AssetCO2EmissionsError::AlreadyPaused),
+                true => (),
+                false => self.ensure_emissions_correct(Some(id), &emissions)?,
+            }
+
+            // Save final CO2 Emissions & emit corresponding events.
+            self.save_new_co2_emissions(&id, &emissions)?;
+
+            // A retired Asset is not owned anymore, so its CO2 Emissions leave the owner's total.
+            let own = self.own_emissions_total(id);
+            self.owner_emissions.insert(
+                owner,
+                &self
+                    .owner_emissions
+                    .get(owner)
+                    .unwrap_or(0)
+                    .saturating_sub(own),
+            );
+            self.remove_owned_asset(&owner, &id)?;
+            self.asset_owner.remove(id);
+            self.retired.insert(id, &true);
+            self.pending_transfers.remove(id);
+            self.active_assets = self.active_assets.saturating_sub(1);
+
+            if self.offers.contains(id) {
+                self.offers.remove(id);
+                self.env().emit_event(OfferRevoked { id });
+            }
+            self.env().emit_event(Retired { id });
+
+            // Refund the storage deposit to the retiring owner.
+            if let Some(deposit) = self.deposits.get(id) {
+                self.deposits.remove(id);
+                self.held_deposits = self.held_deposits.saturating_sub(deposit);
+                self.env()
+                    .transfer(owner, deposit)
+                    .map_err(|_| AssetCO2EmissionsError::TransferFailed)?;
+            }
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn has_paused(&self, id: AssetId) -> Option<bool> {
+            self.paused.get(id)
+        }
+
+        #[ink(message)]
+        fn add_emissions(
+            &mut self,
+            id: AssetId,
+            emissions: CO2Emissions,
+        ) -> Result<(), AssetCO2EmissionsError> {
+            self.ensure_not_retired(&id)?;
+            self.ensure_exists(&id)?;
+            self.ensure_owner(&id, &self.env().caller())?;
+            self.ensure_not_paused(&id)?;
+
+            let emissions: Vec<CO2Emissions> = Vec::from([emissions]);
+            self.ensure_emissions_correct(Some(id), &emissions)?;
+            self.consume_emission_rate(&id)?;
+
+            // Save CO2 Emissions & emit corresponding events.
+            self.save_new_co2_emissions(&id, &emissions)?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn get_asset_emissions(&self, id: AssetId) -> Option<Vec<CO2Emissions>> {
+            self.co2_emissions.get(id)
+        }
+
+        #[ink(message)]
+        fn get_metadata(&self, id: AssetId) -> Option<Metadata> {
+            self.metadata.get(id)
+        }
+
+        #[ink(message)]
+        fn get_parent_details(&self, id: AssetId) -> Option<ParentDetails> {
+            self.parent.get(id)
+        }
+
+        #[ink(message)]
+        fn get_asset(&self, id: AssetId) -> Option<AssetDetails> {
+            // Asset does not exist or is only partially written, return None.
+            Some(AssetDetails {
+                asset_id: id,
+                metadata: self.get_metadata(id)?,
+                emissions: self.get_asset_emissions(id)?,
+                parent: self.get_parent_details(id)?,
+            })
+        }
+
+        #[ink(message)]
+        fn query_emissions(&self, id: AssetId) -> Option<Vec<AssetDetails>> {
+            // Retired Assets keep their full history.
+            match self.ensure_recorded(&id) {
+                Err(_) => None,
+                Ok(_) => Some(self.build_asset_tree(id)),
+            }
+        }
+    }
+
+    /// Unit tests
+    #[cfg(test)]
+    mod tests {
+        use ink::env::test;
+        use ink::env::test::DefaultAccounts;
+        use ink::env::DefaultEnvironment;
+
+        use super::*;
+
+        use ink::primitives::{Clear, Hash};
+
+        type Event = <InfinityAsset as ::ink::reflect::ContractEventBase>::Type;
+
+        fn get_accounts() -> DefaultAccounts<DefaultEnvironment> {
+            test::default_accounts::<DefaultEnvironment>()
+        }
+
+        fn prepare_env() -> (DefaultAccounts<DefaultEnvironment>, InfinityAsset) {
+            // Current block at the default CO2 Emissions date, so default items are not future-dated.
+            test::set_block_timestamp::<DefaultEnvironment>(default_timestamp() * 1000);
+            (get_accounts(), InfinityAsset::new())
+        }
+
+        fn env_with_default_asset() -> (
+            (DefaultAccounts<DefaultEnvironment>, InfinityAsset),
+            (AssetId, AccountId),
+        ) {
+            let (accounts, mut contract) = prepare_env();
+            let asset_owner = accounts.django;
+            let asset_id = blast_default_asset(&mut contract, &asset_owner);
+            ((accounts, contract), (asset_id, asset_owner))
+        }
+
+        fn set_caller(sender: AccountId) {
+            test::set_caller::<DefaultEnvironment>(sender);
+        }
+
+        fn new_emission(
+            category: EmissionsCategory,
+            data_source: DataSource,
+            balanced: bool,
+            value: u128,
+            date: u64,
+        ) -> CO2Emissions {
+            CO2Emissions {
+                category,
+                data_source,
+                balanced,
+                value,
+                uncertainty: 0,
+                date,
+                lot_id: 0,
+                evidence_hash: None,
+                offset_value: 0,
+                methodology: 0,
+            }
+        }
+
+        fn default_data_source() -> Vec<u8> {
+            Vec::from([0u8, 1u8, 2u8, 3u8])
+        }
+
+        fn new_emissions(items: u8) -> Vec<CO2Emissions> {
+            let mut emissions = Vec::new();
+            for i in 0..items {
+                emissions.push(new_emission(
+                    EmissionsCategory::Upstream,
+                    default_data_source(),
+                    true,
+                    i as u128 + 1, // avoid Zero Emissions Item
+                    default_timestamp(),
+                ));
+            }
+            emissions
+        }
+
+        fn normalized(emissions: Vec<CO2Emissions>) -> Vec<CO2Emissions> {
+            emissions
+                .into_iter()
+                .map(InfinityAsset::normalize_offset)
+                .collect()
+        }
+
+        fn default_metadata() -> Vec<u8> {
+            Vec::from([0u8, 1u8, 2u8, 3u8])
+        }
+
+        fn default_timestamp() -> u64 {
+            // 28.04.2023 00:00:00
+            1682632800
+        }
+
+        fn default_emission_item() -> CO2Emissions {
+            let emissions_category = EmissionsCategory::Upstream;
+            let emissions_data_source = default_data_source();
+            let emissions_balanced = true;
+            let emissions_value = 1;
+            let timestamp: u64 = default_timestamp();
+
+            new_emission(
+                emissions_category,
+                emissions_data_source,
+                emissions_balanced,
+                emissions_value,
+                timestamp,
+            )
+        }
+
+        fn blast_default_asset(contract: &mut InfinityAsset, owner: &AccountId) -> AssetId {
+            let metadata = default_metadata();
+            let parent = None;
+
+            let emissions: Vec<CO2Emissions> = new_emissions(1);
+
+            assert!(contract.blast(*owner, metadata, emissions, parent).is_ok());
+
+            contract.next_id - 1
+        }
+
+        /// Blast a chain of `depth` descendants below `root`, returns the deepest Asset id.
+        fn blast_child_chain(
+            contract: &mut InfinityAsset,
+            owner: &AccountId,
+            root: AssetId,
+            depth: u32,
+        ) -> AssetId {
+            set_caller(*owner);
+            let mut asset_id = root;
+            for _ in 0..depth {
+                assert!(contract.pause(asset_id).is_ok());
+                assert!(contract
+                    .blast(
+                        *owner,
+                        default_metadata(),
+                        new_emissions(1),
+                        Some((asset_id, 1))
+                    )
+                    .is_ok());
+                asset_id = contract.next_id - 1;
+            }
+            asset_id
+        }
+
+        /// For calculating the event topic hash.
+        struct PrefixedValue<'a, 'b, T> {
+            pub prefix: &'a [u8],
+            pub value: &'b T,
+        }
+
+        impl<X> scale::Encode for PrefixedValue<'_, '_, X>
+        where
+            X: scale::Encode,
+        {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                self.prefix
+                    .size_hint()
+                    .saturating_add(self.value.size_hint())
+            }
+
+            #[inline]
+            fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+                self.prefix.encode_to(dest);
+                self.value.encode_to(dest);
+            }
+        }
+
+        fn encoded_into_hash<T>(entity: &T) -> Hash
+        where
+            T: scale::Encode,
+        {
+            use ink::{
+                env::hash::{Blake2x256, CryptoHash, HashOutput},
+                primitives::Clear,
+            };
+
+            let mut result = Hash::CLEAR_HASH;
+            let len_result = result.as_ref().len();
+            let encoded = entity.encode();
+            let len_encoded = encoded.len();
+            if len_encoded <= len_result {
+                result.as_mut()[..len_encoded].copy_from_slice(&encoded);
+                return result;
+            }
+            let mut hash_output = <<Blake2x256 as HashOutput>::Type as Default>::default();
+            <Blake2x256 as CryptoHash>::hash(&encoded, &mut hash_output);
+            let copy_len = core::cmp::min(hash_output.len(), len_result);
+            result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
+            result
+        }
+
+        fn assert_blasted_event(
+            event: &test::EmittedEvent,
+            expected_id: AssetId,
+            expected_metadata: Metadata,
+            expected_owner: AccountId,
+            expected_parent: ParentDetails,
+        ) {
+            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("Encountered invalid contract event data buffer");
+            if let Event::Blasted(Blasted {
+                id,
+                metadata,
+                owner,
+                parent,
+            }) = decoded_event
+            {
+                assert_eq!(id, expected_id, "encountered invalid Blasted.id");
+                assert_eq!(
+                    metadata, expected_metadata,
+                    "encountered invalid Blasted.metadata"
+                );
+                assert_eq!(owner, expected_owner, "encountered invalid Blasted.owner");
+                assert_eq!(
+                    parent, expected_parent,
+                    "encountered invalid Blasted.parent"
+                );
+            } else {
+                panic!("encountered unexpected event kind: expected a Blasted event")
+            }
+            let expected_topics = vec![
+                encoded_into_hash(&PrefixedValue {
+                    value: b"InfinityAsset::Blasted",
+                    prefix: b"",
+                }),
+                encoded_into_hash(&PrefixedValue {
+                    prefix: b"InfinityAsset::Blasted::id",
+                    value: &expected_id,
+                }),
+            ];
+            assert_event_topics(expected_topics, event.topics.clone());
+        }
+
+        fn assert_paused_event(event: &test::EmittedEvent, expected_id: AssetId) {
+            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("Encountered invalid contract event data buffer");
+            if let Event::Paused(Paused { id }) = decoded_event {
+                assert_eq!(id, expected_id, "encountered invalid Paused.id");
+            } else {
+                panic!("encountered unexpected event kind: expected a Paused event")
+            }
+            let expected_topics = vec![
+                encoded_into_hash(&PrefixedValue {
+                    value: b"InfinityAsset::Paused",
+                    prefix: b"",
+                }),
+                encoded_into_hash(&PrefixedValue {
+                    prefix: b"InfinityAsset::Paused::id",
+                    value: &expected_id,
+                }),
+            ];
+            assert_event_topics(expected_topics, event.topics.clone());
+        }
+
+        fn assert_unpaused_event(event: &test::EmittedEvent, expected_id: AssetId) {
+            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("Encountered invalid contract event data buffer");
+            if let Event::Unpaused(Unpaused { id }) = decoded_event {
+                assert_eq!(id, expected_id, "encountered invalid Unpaused.id");
+            } else {
+                panic!("encountered unexpected event kind: expected an Unpaused event")
+            }
+            let expected_topics = vec![
+                encoded_into_hash(&PrefixedValue {
+                    value: b"InfinityAsset::Unpaused",
+                    prefix: b"",
+                }),
+                encoded_into_hash(&PrefixedValue {
+                    prefix: b"InfinityAsset::Unpaused::id",
+                    value: &expected_id,
+                }),
+            ];
+            assert_event_topics(expected_topics, event.topics.clone());
+        }
+
+        fn assert_emissions_event(
+            event: &test::EmittedEvent,
+            expected_id: AssetId,
+            expected: &CO2Emissions,
+        ) {
+            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("Encountered invalid contract event data buffer");
+            if let Event::Emission(Emission {
+                id,
+                category,
+                data_source,
+                balanced,
+                date,
+                value,
+                evidence_hash,
+            }) = decoded_event
+            {
+                assert_eq!(id, expected_id, "encountered invalid Emission.id");
+                assert_eq!(
+                    category, expected.category,
+                    "encountered invalid Emission.category"
+                );
+                assert_eq!(
+                    data_source, expected.data_source,
+                    "encountered invalid Emission.data_source"
+                );
+                assert_eq!(
+                    balanced, expected.balanced,
+                    "encountered invalid Emission.balanced"
+                );
+                assert_eq!(date, expected.date, "encountered invalid Emission.date");
+                assert_eq!(value, expected.value, "encountered invalid Emission.value");
+                assert_eq!(
+                    evidence_hash, expected.evidence_hash,
+                    "encountered invalid Emission.evidence_hash"
+                );
+            } else {
+                panic!("encountered unexpected event kind: expected an Emission event")
+            }
+            let expected_topics = vec![
+                encoded_into_hash(&PrefixedValue {
+                    value: b"InfinityAsset::Emission",
+                    prefix: b"",
+                }),
+                encoded_into_hash(&PrefixedValue {
+                    prefix: b"InfinityAsset::Emission::id",
+                    value: &expected_id,
+                }),
+                encoded_into_hash(&PrefixedValue {
+                    prefix: b"InfinityAsset::Emission::category",
+                    value: &expected.category,
+                }),
+            ];
+            assert_event_topics(expected_topics, event.topics.clone());
+        }
+
+        fn assert_transfer_event(
+            event: &test::EmittedEvent,
+            expected_id: AssetId,
+            expected_from: AccountId,
+            expected_to: AccountId,
+        ) {
+            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("Encountered invalid contract event data buffer");
+            if let Event::Transfer(Transfer { id, from, to }) = decoded_event {
+                assert_eq!(id, expected_id, "encountered invalid Transfer.id");
+                assert_eq!(from, expected_from, "encountered invalid Transfer.from");
+                assert_eq!(to, expected_to, "encountered invalid Transfer.to");
+            } else {
+                panic!("encountered unexpected event kind: expected a Transfer event")
+            }
+            let expected_topics = vec![
+                encoded_into_hash(&PrefixedValue {
+                    value: b"InfinityAsset::Transfer",
+                    prefix: b"",
+                }),
+                encoded_into_hash(&PrefixedValue {
+                    prefix: b"InfinityAsset::Transfer::id",
+                    value: &expected_id,
+                }),
+            ];
+            assert_event_topics(expected_topics, event.topics.clone());
+        }
+
+        fn assert_event_topics(expected: Vec<Hash>, topics: Vec<Vec<u8>>) {
+            for (n, (actual_topic, expected_topic)) in topics.iter().zip(expected).enumerate() {
+                let mut topic_hash = Hash::CLEAR_HASH;
+                let len = actual_topic.len();
+                topic_hash.as_mut()[0..len].copy_from_slice(&actual_topic[0..len]);
+
+                assert_eq!(
+                    topic_hash, expected_topic,
+                    "encountered invalid topic at {n}"
+                );
+            }
+        }
+
+        #[ink::test]
+        fn should_set_new_owner() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if proper contract owner set
+            assert_eq!(contract.contract_owner, accounts.alice);
+
+            // Set a new contract owner
+            assert!(contract.set_contract_owner(accounts.bob).is_ok());
+
+            // Check if new contract owner is properly set
+            assert_eq!(contract.contract_owner, accounts.bob);
+        }
+
+        #[ink::test]
+        fn should_reject_set_new_owner() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check initial contract owner
+            assert_eq!(contract.contract_owner, accounts.alice);
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to set a new contract owner as nonowner
+            assert_eq!(
+                contract.set_contract_owner(accounts.bob),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
+
+            // Check if contract owner remain unchanged
+            assert_eq!(contract.contract_owner, accounts.alice);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Only contract owner can set code hash")]
+        fn should_reject_set_code() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check initial contract owner
+            assert_eq!(contract.contract_owner, accounts.alice);
+
+            set_caller(accounts.bob);
+
+            // Check if panic
+            // While trying to set contract code as nonowner
+            contract.set_code([0x0; 32]);
+        }
+
+        #[ink::test]
+        fn should_reject_empty_emissions_during_blast() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+            let parent = None;
+            let emissions: Vec<CO2Emissions> = Vec::new();
+
+            // Check if proper error is returned
+            // While trying to blast asset without CO2 Emissions items
+            assert_eq!(
+                contract.blast(accounts.alice, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::EmissionsEmpty)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_single_zero_emissions_item_during_blast() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+            let parent = None;
+
+            let mut item = default_emission_item();
+            item.value = 0;
+
+            let mut emissions: Vec<CO2Emissions> = new_emissions(1);
+            emissions[0].value = 0;
+
+            // Check if proper error is returned
+            // While trying to blast asset with Zero emissions item
+            assert_eq!(
+                contract.blast(accounts.alice, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::ZeroEmissionsItem)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_zero_emissions_item_in_array_during_blast() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+            let parent = None;
+
+            let mut emissions = new_emissions(3);
+            emissions[1].value = 0;
+
+            // Check if proper error is returned
+            // While trying to blast asset with a Zero emissions item in many items
+            assert_eq!(
+                contract.blast(accounts.alice, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::ZeroEmissionsItem)
+            );
+        }
+
+        #[ink::test]
+        fn should_blast_with_single_emissions_item() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+            let parent = None;
+
+            let emissions: Vec<CO2Emissions> = new_emissions(1);
+
+            let owner = accounts.bob;
+
+            // Check if asset is blasted
+            assert!(contract
+                .blast(owner, metadata.clone(), emissions.clone(), parent)
+                .is_ok());
+
+            let expected_asset_id = 1;
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check events count
+            // 1 * Blasted + 1 * Emission
+            assert_eq!(1 + 1, emitted_events.len());
+
+            // Check Blasted event
+            assert_blasted_event(
+                &emitted_events[0],
+                expected_asset_id,
+                metadata,
+                owner,
+                parent,
+            );
+
+            // Check Emission event
+            assert_emissions_event(&emitted_events[1], expected_asset_id, &emissions[0]);
+        }
+
+        #[ink::test]
+        fn should_blast_with_multiple_emissions_items() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+            let parent = None;
+
+            let emissions_items_count: u8 = 3;
+            let emissions = new_emissions(emissions_items_count);
+
+            let owner = accounts.eve;
+
+            // Check if asset is blasted
+            assert!(contract
+                .blast(owner, metadata.clone(), emissions.clone(), parent)
+                .is_ok());
+
+            let expected_asset_id = 1;
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check proper events count
+            // 1 * Blasted + 3 * Emission
+            assert_eq!(
+                1 + emissions_items_count,
+                test::recorded_events().count() as u8
+            );
+
+            // Check Blasted event for the asset
+            assert_blasted_event(
+                &emitted_events[0],
+                expected_asset_id,
+                metadata,
+                owner,
+                parent,
+            );
+
+            // Check Emission event for each emission item
+            for i in 0..(emissions_items_count as usize) {
+                assert_emissions_event(&emitted_events[i + 1], expected_asset_id, &emissions[i]);
+            }
+        }
+
+        #[ink::test]
+        fn should_nonexistent_get_emissions_work_properly() {
+            let contract = InfinityAsset::new();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.get_asset_emissions(1000).is_none());
+        }
+
+        #[ink::test]
+        fn should_get_emissions_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+            let parent = None;
+
+            let emissions = new_emissions(3);
+
+            let asset_id = 1;
+
+            // Check if asset is blasted
+            assert!(contract
+                .blast(accounts.eve, metadata, emissions.clone(), parent)
+                .is_ok());
+
+            let emissions_from_state = contract.get_asset_emissions(asset_id);
+
+            // Check if contract return proper emissions data
+            assert!(emissions_from_state.is_some());
+            assert!(normalized(emissions)
+                .iter()
+                .eq(emissions_from_state.unwrap().iter()));
+        }
+
+        #[ink::test]
+        fn should_nonexistent_get_metadata_work_properly() {
+            let contract = InfinityAsset::new();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.get_metadata(1000).is_none());
+        }
+
+        #[ink::test]
+        fn should_get_metadata_work_properly() {
+            let ((_accounts, contract), (asset_id, _asset_owner)) = env_with_default_asset();
+
+            let metadata = default_metadata();
+
+            let metadata_from_state = contract.get_metadata(asset_id);
+
+            // Check if contract return proper metadata
+            assert!(metadata_from_state.is_some());
+            assert!(metadata.iter().eq(metadata_from_state.unwrap().iter()));
+        }
+
+        #[ink::test]
+        fn should_nonexistent_get_parent_work_properly() {
+            let contract = InfinityAsset::new();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.get_parent_details(1000).is_none());
+        }
+
+        #[ink::test]
+        fn should_get_parent_for_root_asset_work_properly() {
+            let ((_accounts, contract), (asset_id, _asset_owner)) = env_with_default_asset();
+
+            let parent = None;
+
+            let parent_from_state = contract.get_parent_details(asset_id);
+
+            // Check if contract return proper asset's parent
+            assert!(parent_from_state.is_some());
+            assert_eq!(parent, parent_from_state.unwrap());
+        }
+
+        #[ink::test]
+        fn should_owner_of_work_properly() {
+            let ((_accounts, contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            let owner_from_state = contract.owner_of(asset_id);
+
+            // Check if contract return proper asset's owner
+            assert!(owner_from_state.is_some());
+            assert_eq!(asset_owner, owner_from_state.unwrap());
+        }
+
+        #[ink::test]
+        fn should_already_blasted_asset_not_be_paused() {
+            let ((_accounts, contract), (asset_id, _asset_owner)) = env_with_default_asset();
+
+            let paused = contract.has_paused(asset_id);
+
+            // Check if asset is not paused
+            assert!(paused.is_some());
+            assert!(!paused.unwrap());
+        }
+
+        #[ink::test]
+        fn should_not_owner_not_be_able_to_set_paused_state() {
+            let ((accounts, mut contract), (asset_id, _asset_owner)) = env_with_default_asset();
+
+            set_caller(accounts.eve);
+
+            // Check if proper error is returned
+            // While trying to pause an asset as nonowner
+            assert_eq!(
+                contract.pause(asset_id),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn should_owner_be_able_to_set_paused_state() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Check if `pause` work
+            assert!(contract.pause(asset_id).is_ok());
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check events count
+            // 1* Blasted + 1 * Emission + 1 * Paused
+            assert_eq!(1 + 1 + 1, test::recorded_events().count());
+
+            // Check Paused event
+            assert_paused_event(&emitted_events[2], asset_id);
+        }
+
+        #[ink::test]
+        fn should_owner_not_be_able_to_set_paused_state_while_already_paused() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Pause the asset
+            assert!(contract.pause(asset_id).is_ok());
+
+            // Check if proper error is returned
+            // While trying to pause already paused asset
+            assert_eq!(
+                contract.pause(asset_id),
+                Err(AssetCO2EmissionsError::AlreadyPaused)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_non_existent_parent_in_blast() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+
+            let emissions: Vec<CO2Emissions> = new_emissions(1);
+
+            let owner = accounts.alice;
+
+            let parent: ParentDetails = Some((1000, 1));
+
+            set_caller(owner);
+
+            // Check if proper error is returned
+            // While trying to blast asset with nonexistent parent
+            assert_eq!(
+                contract.blast(owner, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::AssetNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_not_owner_creating_child_in_blast() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            let emissions = new_emissions(1);
+            let metadata = default_metadata();
+
+            let parent: ParentDetails = Some((asset_id, 1));
+
+            set_caller(accounts.alice);
+
+            // Check if proper error is returned
+            // While trying to blast child asset as parent nonowner
+            assert_eq!(
+                contract.blast(asset_owner, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_not_paused_in_blast() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            let emissions = new_emissions(1);
+            let metadata = default_metadata();
+
+            set_caller(asset_owner);
+
+            let parent: ParentDetails = Some((asset_id, 1));
+
+            // Check if proper error is returned
+            // While trying to blast a child asset for not paused parent
+            assert_eq!(
+                contract.blast(asset_owner, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::NotPaused)
+            );
+        }
+
+        #[ink::test]
+        fn should_blast_child() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            let metadata = default_metadata();
+            let emissions = new_emissions(1);
+            let parent: ParentDetails = Some((asset_id, 1));
+
+            // Pause parent asset
+            assert!(contract.pause(asset_id).is_ok());
+
+            // Blast child asset
+            assert!(contract
+                .blast(asset_owner, metadata.clone(), emissions.clone(), parent)
+                .is_ok());
+
+            let expected_asset_id = 2;
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check events count
+            // 1 * Blasted + 1 * Emission + 1 * Paused + 1 * Blasted + 1 * Emission
+            assert_eq!(1 + 1 + 1 + 1 + 1, emitted_events.len());
+
+            // Check Blasted event
+            assert_blasted_event(
+                &emitted_events[3],
+                expected_asset_id,
+                metadata,
+                asset_owner,
+                parent,
+            );
+
+            // Check Emission event for already blasted child asset
+            assert_emissions_event(&emitted_events[4], expected_asset_id, &emissions[0]);
+
+            // Check child asset's parent
+            let parent_from_state = contract.get_parent_details(expected_asset_id);
+            assert!(parent_from_state.is_some());
+            assert_eq!(parent, parent_from_state.unwrap());
+        }
+
+        #[ink::test]
+        fn should_not_add_emissions_to_nonexistent_asset() {
+            let mut contract = InfinityAsset::new();
+
+            let item = default_emission_item();
+
+            let asset_id = 1;
+
+            // Check if proper error is returned
+            // While trying to add CO2 Emissions to nonexistent asset
+            assert_eq!(
+                contract.add_emissions(asset_id, item),
+                Err(AssetCO2EmissionsError::AssetNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn should_not_owner_not_be_able_to_add_emissions() {
+            let ((accounts, mut contract), (asset_id, _asset_owner)) = env_with_default_asset();
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to add CO2 Emissions as asset's nonowner
+            assert_eq!(
+                contract.add_emissions(asset_id, default_emission_item()),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_paused_in_add_emissions() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Pause the asset first
+            assert!(contract.pause(asset_id).is_ok());
+
+            // Check if proper error is returned
+            // While trying to add CO2 Emissions in Paused state
+            assert_eq!(
+                contract.add_emissions(asset_id, default_emission_item()),
+                Err(AssetCO2EmissionsError::AlreadyPaused)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_zero_emissions_item_in_add_emissions() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Prepare Zero emissions item
+            let mut new_emission_item = default_emission_item();
+            new_emission_item.value = 0u128;
+
+            // Check if proper error is returned
+            // While trying to add Zero emissions item in `add_emissions`
+            assert_eq!(
+                contract.add_emissions(asset_id, new_emission_item),
+                Err(AssetCO2EmissionsError::ZeroEmissionsItem)
+            );
+        }
+
+        #[ink::test]
+        fn should_owner_be_able_to_add_emissions() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            let emission_item = default_emission_item();
+
+            // Add CO2 Emissions item
+            assert!(contract
+                .add_emissions(asset_id, emission_item.clone())
+                .is_ok());
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check events count
+            // 1 * Blasted + 1 * Emissions + 1 * Emissions
+            assert_eq!(1 + 1 + 1, emitted_events.len());
+            assert_emissions_event(&emitted_events[2], asset_id, &emission_item);
+
+            let expected_emissions: Vec<CO2Emissions> =
+                normalized(Vec::from([default_emission_item(), emission_item]));
+            let emissions_from_state = contract.get_asset_emissions(asset_id);
+
+            // Check if contract return proper CO2 Emissions data
+            assert!(emissions_from_state.is_some());
+            assert!(expected_emissions
+                .iter()
+                .eq(emissions_from_state.unwrap().iter()));
+        }
+
+        #[ink::test]
+        fn should_not_transfer_nonexistent_asset() {
+            let (accounts, mut contract) = prepare_env();
+
+            let item = default_emission_item();
+
+            let asset_id = 1;
+
+            set_caller(accounts.alice);
+
+            // Check if proper error is returned
+            // While trying to transfer nonexistent asset
+            assert_eq!(
+                contract.transfer(accounts.bob, asset_id, Vec::from([item])),
+                Err(AssetCO2EmissionsError::AssetNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn should_not_owner_not_be_able_to_transfer() {
+            let ((accounts, mut contract), (asset_id, _asset_owner)) = env_with_default_asset();
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to transfer asset as nonowner
+            assert_eq!(
+                contract.transfer(accounts.bob, asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_transfer_in_paused() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Pause an asset
+            assert!(contract.pause(asset_id).is_ok());
+
+            // Check if proper error is returned
+            // While trying to transfer asset in Paused state
+            assert_eq!(
+                contract.transfer(accounts.bob, asset_id, new_emissions(2)),
+                Err(AssetCO2EmissionsError::AlreadyPaused)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_empty_emissions_in_transfer() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Check if proper error is returned
+            // While trying to transfer asset with empty vector of CO2 Emissions items
+            assert_eq!(
+                contract.transfer(accounts.bob, asset_id, Vec::new()),
+                Err(AssetCO2EmissionsError::EmissionsEmpty)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_too_many_emissions_on_blast() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+            let parent = None;
+
+            let emissions: Vec<CO2Emissions> = new_emissions(MAX_EMISSIONS_PER_ASSET + 1);
+
+            let expected_asset_id = 1;
+            let owner = accounts.alice;
+
+            set_caller(owner);
+
+            // Check if proper error is returned
+            // While trying to blast asset with too many emissions items
+            assert_eq!(
+                contract.blast(owner, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::EmissionsOverflow)
+            );
+
+            // Check if asset is not blasted
+            assert!(contract.get_asset(expected_asset_id).is_none());
+        }
+
+        #[ink::test]
+        fn should_reject_too_many_emissions_on_add() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+            let parent = None;
+
+            let emissions: Vec<CO2Emissions> = new_emissions(MAX_EMISSIONS_PER_ASSET);
+
+            let asset_id = 1;
+            let owner = accounts.alice;
+
+            set_caller(owner);
+
+            // Blast asset with max emissions items count
+            assert!(contract.blast(owner, metadata, emissions, parent).is_ok());
+
+            let item = default_emission_item();
+
+            // Check if proper error is returned
+            // While trying to add emission item causing overflow
+            assert_eq!(
+                contract.add_emissions(asset_id, item.clone()),
+                Err(AssetCO2EmissionsError::EmissionsOverflow)
+            );
+
+            // Check if proper error is returned
+            // While trying to transfer with emission item causing overflow
+            assert_eq!(
+                contract.transfer(accounts.bob, asset_id, Vec::from([item])),
+                Err(AssetCO2EmissionsError::EmissionsOverflow)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_too_many_chars_in_data_source() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata = default_metadata();
+
+            let parent = None;
+
+            let emissions_data_source = vec![1u8; MAX_DATA_SOURCE_LENGTH as usize + 1];
+            let mut item = default_emission_item();
+            item.data_source = emissions_data_source;
+
+            let emissions: Vec<CO2Emissions> = Vec::from([item]);
+
+            let owner = accounts.alice;
+
+            set_caller(owner);
+
+            let expected_asset_id = 1;
+            let owner = accounts.alice;
+
+            set_caller(owner);
+
+            // Check if proper error is returned
+            // While trying to blast asset with emissions item `data_source` overflow
+            assert_eq!(
+                contract.blast(owner, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::DataSourceOverflow)
+            );
+
+            // Check if asset is not blasted
+            assert!(contract.get_asset(expected_asset_id).is_none());
+        }
+
+        #[ink::test]
+        fn should_reject_zero_emissions_item_in_transfer() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            let mut emissions = new_emissions(4);
+            emissions[2].value = 0;
+
+            // Check if proper error is returned
+            // While trying to transfer with Zero emission item
+            assert_eq!(
+                contract.transfer(accounts.bob, asset_id, emissions),
+                Err(AssetCO2EmissionsError::ZeroEmissionsItem)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_too_many_chars_in_metadata() {
+            let (accounts, mut contract) = prepare_env();
+
+            let metadata: Metadata = vec![0u8; MAX_METADATA_LENGTH as usize + 1];
+
+            let parent = None;
+
+            let emissions = new_emissions(1);
+
+            let expected_asset_id = 1;
+            let owner = accounts.alice;
+
+            set_caller(owner);
+
+            // Check if proper error is returned
+            // While trying to blast asset with `metadata` overflow
+            assert_eq!(
+                contract.blast(owner, metadata, emissions, parent),
+                Err(AssetCO2EmissionsError::MetadataOverflow)
+            );
+
+            // Check if asset is not blasted
+            assert!(contract.get_asset(expected_asset_id).is_none());
+        }
+
+        #[ink::test]
+        fn should_owner_be_able_to_transfer() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            let _item = default_emission_item();
+
+            let new_owner = accounts.bob;
+
+            let emissions = new_emissions(1);
+
+            // Transfer asset
+            assert!(contract
+                .transfer(new_owner, asset_id, emissions.clone())
+                .is_ok());
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check emitted evetns count
+            // 1 * Blasted + 1 * Emission + 1 * Transfer + 1 * Emission
+            assert_eq!(1 + 1 + 1 + 1, emitted_events.len());
+
+            // Check Transfer event
+            assert_transfer_event(&emitted_events[2], asset_id, asset_owner, new_owner);
+
+            // Check Emission event
+            assert_emissions_event(&emitted_events[3], asset_id, &emissions[0]);
+
+            let expected_emissions: Vec<CO2Emissions> =
+                normalized(Vec::from([default_emission_item(), emissions[0].clone()]));
+            let emissions_from_state = contract.get_asset_emissions(asset_id);
+
+            // Check asset's CO2 Emissions items
+            assert!(emissions_from_state.is_some());
+            assert!(expected_emissions
+                .iter()
+                .eq(emissions_from_state.unwrap().iter()));
+
+            // Check asset's owner
+            let owner_from_state = contract.owner_of(asset_id);
+            assert!(owner_from_state.is_some());
+            assert_eq!(new_owner, owner_from_state.unwrap());
+        }
+
+        #[ink::test]
+        fn should_nonexistent_asset_query_emissions_work_properly() {
+            let contract = InfinityAsset::new();
+            assert!(contract.query_emissions(69).is_none());
+        }
+
+        #[ink::test]
+        fn should_query_emissions_for_single_asset_work_properly() {
+            let ((_accounts, contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            let emissions = Vec::from([default_emission_item()]);
+            let metadata: Metadata = default_metadata();
+            let parent = None;
+
+            let expected_value: Vec<AssetDetails> = Vec::from([AssetDetails {
+                asset_id,
+                metadata,
+                emissions: normalized(emissions),
+                parent,
+            }]);
+
+            let details_from_state = contract.query_emissions(asset_id);
+
+            // Check single asset tree
+            assert!(details_from_state.is_some());
+            assert_eq!(expected_value, details_from_state.unwrap());
+        }
+
+        #[ink::test]
+        fn should_query_emissions_for_longer_path_work_properly() {
+            let ((_accounts, mut contract), (mut asset_id, asset_owner)) = env_with_default_asset();
+
+            let metadata = default_metadata();
+
+            let emissions: Vec<CO2Emissions> = Vec::from([default_emission_item()]);
+
+            let mut expected_tree_path: Vec<AssetDetails> = Vec::from([AssetDetails {
+                asset_id,
+                metadata: metadata.clone(),
+                emissions: normalized(emissions),
+                parent: None,
+            }]);
+
+            let timestamp = 1_000_000_000u64;
+
+            set_caller(asset_owner);
+            // create token tree path of maximum depth
+            for i in 1..=u128::from(MAX_TREE_DEPTH) {
+                let parent: ParentDetails = Some((asset_id, 1));
+
+                let mut emissions = new_emissions(1);
+                emissions[0].value = i;
+                emissions[0].date = timestamp + i as u64;
+
+                // Pause an asset
+                assert!(contract.pause(asset_id).is_ok());
+
+                // Blast child asset
+                assert!(contract
+                    .blast(asset_owner, metadata.clone(), emissions.clone(), parent)
+                    .is_ok());
+
+                asset_id += 1;
+                expected_tree_path.insert(
+                    0,
+                    AssetDetails {
+                        asset_id,
+                        metadata: metadata.clone(),
+                        emissions: normalized(emissions),
+                        parent,
+                    },
+                );
+            }
+
+            let details_from_state = contract.query_emissions(asset_id);
+
+            // Check extended asset tree
+            assert!(details_from_state.is_some());
+            assert_eq!(expected_tree_path, details_from_state.unwrap());
+        }
+
+        #[ink::test]
+        fn should_list_asset_for_empty_account_work_properly() {
+            let (accounts, contract) = prepare_env();
+
+            // Check if contract return proper value for `query_emissions`
+            assert_eq!(contract.list_assets(accounts.alice), Vec::<AssetId>::new());
+        }
+
+        #[ink::test]
+        fn should_list_asset_for_single_asset_work_properly() {
+            let ((_accounts, contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Check if contract return proper value for `list_assets`
+            assert_eq!(
+                Vec::<AssetId>::from([asset_id]),
+                contract.list_assets(asset_owner)
+            );
+        }
+
+        #[ink::test]
+        fn should_list_assets_for_many_assets_work_properly() {
+            let ((_accounts, mut contract), (mut asset_id, asset_owner)) = env_with_default_asset();
+
+            let metadata = default_metadata();
+
+            let timestamp = 1_000_000_000u64;
+
+            set_caller(asset_owner);
+
+            // create long token tree paths, starting a new tree at the maximum depth
+            for i in 1..1_000 {
+                let parent: ParentDetails =
+                    (i % u128::from(MAX_TREE_DEPTH + 1) != 0).then_some((asset_id, 1));
+                let mut emissions = new_emissions(1);
+                emissions[0].value = i;
+                emissions[0].date = timestamp + i as u64;
+
+                // Pause an asset
+                assert!(contract.pause(asset_id).is_ok());
+                // Blast a child
+                assert!(contract
+                    .blast(asset_owner, metadata.clone(), emissions.clone(), parent)
+                    .is_ok());
+
+                asset_id += 1;
+            }
+
+            let mut assets_from_state = Vec::new();
+            loop {
+                let start = assets_from_state.last().map_or(0, |id| id + 1);
+                let page = contract.list_assets_paged(asset_owner, start, MAX_LIST_RETURN.into());
+                if page.is_empty() {
+                    break;
+                }
+                assets_from_state.extend(page);
+            }
+
+            // Check if contract return proper value
+            assert_eq!((1..1_001).collect::<Vec<AssetId>>(), assets_from_state);
+        }
+
+        #[ink::test]
+        fn should_list_assets_be_capped() {
+            let (accounts, mut contract) = prepare_env();
+
+            for _ in 0..=MAX_LIST_RETURN {
+                assert!(contract
+                    .blast(accounts.bob, default_metadata(), new_emissions(1), None)
+                    .is_ok());
+            }
+
+            // Check if contract return assets with the lowest ids truncated to the cap
+            assert_eq!(
+                (1..=MAX_LIST_RETURN.into()).collect::<Vec<AssetId>>(),
+                contract.list_assets(accounts.bob)
+            );
+        }
+
+        #[ink::test]
+        fn should_list_asset_after_transfer_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            let new_owner = accounts.bob;
+
+            set_caller(asset_owner);
+
+            // Check owned assets before `transfer`
+            assert_eq!(Vec::from([asset_id]), contract.list_assets(asset_owner));
+            assert_eq!(Vec::<AssetId>::new(), contract.list_assets(new_owner));
+
+            // Transfer asset
+            assert!(contract
+                .transfer(new_owner, asset_id, new_emissions(1))
+                .is_ok());
+
+            // Check owned assets after `transfer`
+            assert_eq!(Vec::<AssetId>::new(), contract.list_assets(asset_owner));
+            assert_eq!(Vec::from([asset_id]), contract.list_assets(new_owner));
+        }
+
+        #[ink::test]
+        fn should_nonexistent_emission_trend_work_properly() {
+            let contract = InfinityAsset::new();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.emission_trend(1000).is_none());
+        }
+
+        #[ink::test]
+        fn should_emission_trend_be_positive_for_increasing_series() {
+            let (accounts, mut contract) = prepare_env();
+
+            let mut emissions = new_emissions(4);
+            for (i, item) in emissions.iter_mut().enumerate() {
+                item.value = (i as u128 + 1) * 10;
+                item.date = default_timestamp() + i as u64;
+            }
+
+            // Blast asset with increasing emissions: 10, 20, 30, 40
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check if trend equals (30 + 40) - (10 + 20)
+            assert_eq!(contract.emission_trend(1), Some(40));
+        }
+
+        #[ink::test]
+        fn should_emission_trend_be_negative_for_decreasing_series() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Records are stored out of date order to confirm they get sorted by date
+            let mut emissions = new_emissions(3);
+            emissions[0].value = 5;
+            emissions[0].date = default_timestamp() + 2;
+            emissions[1].value = 50;
+            emissions[1].date = default_timestamp();
+            emissions[2].value = 20;
+            emissions[2].date = default_timestamp() + 1;
+
+            // Blast asset with decreasing emissions over time: 50, 20, 5
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check if trend equals 5 - 50, the middle record is skipped
+            assert_eq!(contract.emission_trend(1), Some(-45));
+        }
+
+        #[ink::test]
+        fn should_emission_trend_be_zero_for_flat_series() {
+            let (accounts, mut contract) = prepare_env();
+
+            let mut emissions = new_emissions(4);
+            for (i, item) in emissions.iter_mut().enumerate() {
+                item.value = 7;
+                item.date = default_timestamp() + i as u64;
+            }
+
+            // Blast asset with flat emissions
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check if trend is flat
+            assert_eq!(contract.emission_trend(1), Some(0));
+
+            // Check if single record asset has a flat trend
+            let asset_id = blast_default_asset(&mut contract, &accounts.bob);
+            assert_eq!(contract.emission_trend(asset_id), Some(0));
+        }
+
+        #[ink::test]
+        fn should_assets_with_metadata_prefix_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            let owner = accounts.alice;
+            for metadata in [&b"SKU-1:slab"[..], b"PLT-7:plate", b"SKU-2:billet", b"SK"] {
+                assert!(contract
+                    .blast(owner, metadata.to_vec(), new_emissions(1), None)
+                    .is_ok());
+            }
+
+            // Check if only Assets sharing the prefix are returned
+            assert_eq!(
+                contract.assets_with_metadata_prefix(b"SKU-".to_vec(), 0, 10),
+                Vec::from([1, 3])
+            );
+
+            // Check if an empty prefix matches every Asset
+            assert_eq!(
+                contract.assets_with_metadata_prefix(Vec::new(), 0, 10),
+                Vec::from([1, 2, 3, 4])
+            );
+
+            // Check if an unknown prefix matches nothing
+            assert!(contract
+                .assets_with_metadata_prefix(b"XYZ".to_vec(), 0, 10)
+                .is_empty());
+        }
+
+        #[ink::test]
+        fn should_assets_with_metadata_prefix_be_paginated() {
+            let (accounts, mut contract) = prepare_env();
+
+            let owner = accounts.alice;
+            for _ in 0..5 {
+                assert!(contract
+                    .blast(owner, b"SKU".to_vec(), new_emissions(1), None)
+                    .is_ok());
+            }
+
+            // Check consecutive pages
+            assert_eq!(
+                contract.assets_with_metadata_prefix(b"SKU".to_vec(), 0, 2),
+                Vec::from([1, 2])
+            );
+            assert_eq!(
+                contract.assets_with_metadata_prefix(b"SKU".to_vec(), 2, 2),
+                Vec::from([3, 4])
+            );
+            assert_eq!(
+                contract.assets_with_metadata_prefix(b"SKU".to_vec(), 4, 2),
+                Vec::from([5])
+            );
+
+            // Check if a page past the last Asset is empty
+            assert!(contract
+                .assets_with_metadata_prefix(b"SKU".to_vec(), 5, 2)
+                .is_empty());
+        }
+
+        #[ink::test]
+        fn should_audit_ownership_consistency_flag_corrupted_indexes() {
+            let (accounts, mut contract) = prepare_env();
+
+            for _ in 0..3 {
+                blast_default_asset(&mut contract, &accounts.django);
+            }
+
+            // Check if consistent indexes report nothing
+            assert_eq!(contract.audit_ownership_consistency(0, 10), Ok(Vec::new()));
+
+            // Corrupt owned Assets index for Asset 2
+            contract
+                .owned_assets
+                .get_mut(&accounts.django)
+                .unwrap()
+                .remove(&2);
+
+            // Corrupt Asset owner index for Asset 3
+            contract.asset_owner.remove(3);
+
+            // Check if both corrupted Assets are flagged
+            assert_eq!(
+                contract.audit_ownership_consistency(0, 10),
+                Ok(Vec::from([2, 3]))
+            );
+
+            // Check if the scan range is respected
+            assert_eq!(
+                contract.audit_ownership_consistency(2, 1),
+                Ok(Vec::from([3]))
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_audit_ownership_consistency_by_not_owner() {
+            let (accounts, contract) = prepare_env();
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to audit ownership as nonowner
+            assert_eq!(
+                contract.audit_ownership_consistency(0, 10),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
+        }
+
+        #[ink::test]
+        fn should_set_reject_duplicate_emissions_be_owner_only() {
+            let (accounts, mut contract) = prepare_env();
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to configure duplicates rejection as nonowner
+            assert_eq!(
+                contract.set_reject_duplicate_emissions(true),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
+            assert!(!contract.reject_duplicate_emissions);
+        }
+
+        #[ink::test]
+        fn should_accept_duplicated_emissions_by_default() {
+            let (accounts, mut contract) = prepare_env();
+
+            let emissions = Vec::from([default_emission_item(), default_emission_item()]);
+
+            // Check if duplicates are accepted when rejection is off
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn should_reject_duplicated_emissions_in_strict_mode() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Turn on duplicates rejection
+            assert!(contract.set_reject_duplicate_emissions(true).is_ok());
+
+            let mut emissions = new_emissions(3);
+            emissions.push(emissions[1].clone());
+
+            // Check if proper error is returned
+            // While trying to blast asset with a duplicated emissions item
+            assert_eq!(
+                contract.blast(accounts.alice, default_metadata(), emissions, None),
+                Err(AssetCO2EmissionsError::DuplicateEmissionInBatch)
+            );
+
+            // Check if distinct emissions items pass
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), new_emissions(3), None)
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn should_transfer_count_of_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.transfer_count_of(1000).is_none());
+
+            // Check if newly blasted asset has not been transferred
+            assert_eq!(contract.transfer_count_of(asset_id), Some(0));
+
+            // Transfer asset back and forth
+            for (from, to) in [
+                (asset_owner, accounts.bob),
+                (accounts.bob, accounts.eve),
+                (accounts.eve, asset_owner),
+            ] {
+                set_caller(from);
+                assert!(contract.transfer(to, asset_id, new_emissions(1)).is_ok());
+            }
+
+            // Check if every transfer has been counted
+            assert_eq!(contract.transfer_count_of(asset_id), Some(3));
+        }
+
+        #[ink::test]
+        fn should_offer_and_claim_asset() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            let buyer = accounts.bob;
+
+            set_caller(asset_owner);
+
+            // Offer the asset to the buyer
+            assert!(contract.offer(asset_id, buyer).is_ok());
+
+            // Check if asset is not transferred yet
+            assert_eq!(contract.owner_of(asset_id), Some(asset_owner));
+
+            set_caller(buyer);
+
+            // Claim the offered asset
+            let emissions = new_emissions(1);
+            assert!(contract.claim(asset_id, emissions.clone()).is_ok());
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check events count
+            // 1 * Blasted + 1 * Emission + 1 * Offered + 1 * Transfer + 1 * Emission + 1 * Claimed
+            assert_eq!(1 + 1 + 1 + 1 + 1 + 1, emitted_events.len());
+
+            // Check Transfer event
+            assert_transfer_event(&emitted_events[3], asset_id, asset_owner, buyer);
+
+            // Check if asset is transferred to the buyer with claim emissions
+            assert_eq!(contract.owner_of(asset_id), Some(buyer));
+            assert_eq!(contract.list_assets(buyer), Vec::from([asset_id]));
+            assert_eq!(
+                contract.get_asset_emissions(asset_id),
+                Some(normalized(Vec::from([
+                    default_emission_item(),
+                    emissions[0].clone()
+                ])))
+            );
+
+            // Check if offer is consumed
+            assert!(!contract.offers.contains(asset_id));
+        }
+
+        #[ink::test]
+        fn should_reject_claim_by_not_offered_account() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(accounts.eve);
+
+            // Check if proper error is returned
+            // While trying to claim an asset without any offer
+            assert_eq!(
+                contract.claim(asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::NotOffered)
+            );
+
+            set_caller(asset_owner);
+            assert!(contract.offer(asset_id, accounts.bob).is_ok());
+
+            set_caller(accounts.eve);
+
+            // Check if proper error is returned
+            // While trying to claim an asset offered to another account
+            assert_eq!(
+                contract.claim(asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::NotOffered)
+            );
+            assert_eq!(contract.owner_of(asset_id), Some(asset_owner));
+        }
+
+        #[ink::test]
+        fn should_revoke_offer() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Check if proper error is returned
+            // While trying to revoke nonexistent offer
+            assert_eq!(
+                contract.revoke_offer(asset_id),
+                Err(AssetCO2EmissionsError::OfferNotFound)
+            );
+
+            assert!(contract.offer(asset_id, accounts.bob).is_ok());
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to revoke an offer as nonowner
+            assert_eq!(
+                contract.revoke_offer(asset_id),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+
+            set_caller(asset_owner);
+            assert!(contract.revoke_offer(asset_id).is_ok());
+
+            set_caller(accounts.bob);
+
+            // Check if revoked offer cannot be claimed
+            assert_eq!(
+                contract.claim(asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::NotOffered)
+            );
+        }
+
+        #[ink::test]
+        fn should_year_of_convert_timestamps_properly() {
+            // 01.01.1970 00:00:00
+            assert_eq!(InfinityAsset::year_of(0), 1970);
+            // 31.12.1999 23:59:59
+            assert_eq!(InfinityAsset::year_of(946_684_799), 1999);
+            // 29.02.2000 12:00:00
+            assert_eq!(InfinityAsset::year_of(951_825_600), 2000);
+            // 28.04.2023 00:00:00
+            assert_eq!(InfinityAsset::year_of(default_timestamp()), 2023);
+            // 31.12.2024 23:59:59
+            assert_eq!(InfinityAsset::year_of(1_735_689_599), 2024);
+            // 01.01.2025 00:00:00
+            assert_eq!(InfinityAsset::year_of(1_735_689_600), 2025);
+        }
+
+        #[ink::test]
+        fn should_emissions_by_year_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.emissions_by_year(1000).is_none());
+
+            let mut emissions = new_emissions(4);
+            // 31.12.2022 23:59:59
+            emissions[0].date = 1_672_531_199;
+            emissions[0].value = 10;
+            // 01.01.2023 00:00:00
+            emissions[1].date = 1_672_531_200;
+            emissions[1].value = 20;
+            // 15.06.2022 00:00:00
+            emissions[2].date = 1_655_251_200;
+            emissions[2].value = 5;
+            // 28.04.2023 00:00:00
+            emissions[3].date = default_timestamp();
+            emissions[3].value = 1;
+
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check if emissions are summed per year in ascending order
+            assert_eq!(
+                contract.emissions_by_year(1),
+                Some(Vec::from([(2022, 15), (2023, 21)]))
+            );
+        }
+
+        #[ink::test]
+        fn should_lineage_size_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.lineage_size(1000).is_none());
+
+            // Check root asset
+            assert_eq!(contract.lineage_size(asset_id), Some(1));
+
+            // Check chain of depth 5
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 4);
+            assert_eq!(contract.lineage_size(leaf_id), Some(5));
+
+            // Check long chain of maximum depth
+            let root_id = blast_default_asset(&mut contract, &accounts.eve);
+            let leaf_id = blast_child_chain(&mut contract, &accounts.eve, root_id, MAX_TREE_DEPTH);
+            assert_eq!(contract.lineage_size(leaf_id), Some(MAX_TREE_DEPTH + 1));
+            assert_eq!(
+                contract.query_emissions(leaf_id).map(|tree| tree.len()),
+                Some(MAX_TREE_DEPTH as usize + 1)
+            );
+        }
+
+        #[ink::test]
+        fn should_total_with_uncertainty_accumulate_across_tree() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.total_with_uncertainty(1000).is_none());
+
+            // Check if uncertainty of the default item is zero
+            assert_eq!(contract.total_with_uncertainty(asset_id), Some((1, 0)));
+
+            set_caller(asset_owner);
+
+            let mut item = default_emission_item();
+            item.value = 100;
+            item.uncertainty = 5;
+            assert!(contract.add_emissions(asset_id, item).is_ok());
+            assert!(contract.pause(asset_id).is_ok());
+
+            let mut emissions = new_emissions(2);
+            emissions[0].value = 40;
+            emissions[0].uncertainty = 2;
+            emissions[1].value = 60;
+            emissions[1].uncertainty = 3;
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    emissions,
+                    Some((asset_id, 1))
+                )
+                .is_ok());
+
+            // Check if uncertainty accumulates alongside the value across the tree
+            assert_eq!(contract.total_with_uncertainty(2), Some((201, 10)));
+        }
+
+        #[ink::test]
+        fn should_assets_with_unbalanced_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            let owner = accounts.django;
+
+            // Check if account without assets has nothing outstanding
+            assert!(contract.assets_with_unbalanced(owner).is_empty());
+
+            // Blast fully balanced asset
+            let balanced_id = blast_default_asset(&mut contract, &owner);
+
+            // Blast partially unbalanced asset
+            let mut emissions = new_emissions(3);
+            emissions[1].balanced = false;
+            assert!(contract
+                .blast(owner, default_metadata(), emissions, None)
+                .is_ok());
+            let unbalanced_id = contract.next_id - 1;
+
+            // Blast balanced asset and add unbalanced emissions later
+            let later_id = blast_default_asset(&mut contract, &owner);
+            let mut item = default_emission_item();
+            item.balanced = false;
+            set_caller(owner);
+            assert!(contract.add_emissions(later_id, item).is_ok());
+
+            // Blast unbalanced asset for another owner
+            let mut emissions = new_emissions(1);
+            emissions[0].balanced = false;
+            assert!(contract
+                .blast(accounts.eve, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check if only the owner's partially unbalanced assets are listed
+            let outstanding = contract.assets_with_unbalanced(owner);
+            assert_eq!(outstanding, Vec::from([unbalanced_id, later_id]));
+            assert!(!outstanding.contains(&balanced_id));
+        }
+
+        #[ink::test]
+        fn should_assets_with_unbalanced_be_bounded() {
+            let (accounts, mut contract) = prepare_env();
+
+            let mut emissions = new_emissions(1);
+            emissions[0].balanced = false;
+
+            // Check if returned assets are truncated to the cap
+            for _ in 0..=MAX_LIST_RETURN {
+                assert!(contract
+                    .blast(accounts.bob, default_metadata(), emissions.clone(), None)
+                    .is_ok());
+            }
+            assert_eq!(
+                (1..=MAX_LIST_RETURN.into()).collect::<Vec<AssetId>>(),
+                contract.assets_with_unbalanced(accounts.bob)
+            );
+
+            // Check if assets beyond the scanned ones are not examined
+            for _ in 0..MAX_SCAN_LENGTH {
+                blast_default_asset(&mut contract, &accounts.eve);
+            }
+            assert!(contract
+                .blast(accounts.eve, default_metadata(), emissions, None)
+                .is_ok());
+            assert!(contract.assets_with_unbalanced(accounts.eve).is_empty());
+        }
+
+        #[ink::test]
+        fn should_pause_batch_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            let owner = accounts.django;
+            let ids: Vec<AssetId> = (0..3)
+                .map(|_| blast_default_asset(&mut contract, &owner))
+                .collect();
+
+            set_caller(owner);
+
+            // Pause all assets at once
+            assert!(contract.pause_batch(ids.clone()).is_ok());
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check events count
+            // 3 * (1 * Blasted + 1 * Emission) + 3 * Paused
+            assert_eq!(3 * (1 + 1) + 3, emitted_events.len());
+
+            // Check Paused event and state for each asset
+            for (i, id) in ids.iter().enumerate() {
+                assert_paused_event(&emitted_events[6 + i], *id);
+                assert_eq!(contract.has_paused(*id), Some(true));
+            }
+        }
+
+        #[ink::test]
+        fn should_pause_batch_reject_whole_batch() {
+            let (accounts, mut contract) = prepare_env();
+
+            let owner = accounts.django;
+            let first_id = blast_default_asset(&mut contract, &owner);
+            let foreign_id = blast_default_asset(&mut contract, &accounts.eve);
+            let last_id = blast_default_asset(&mut contract, &owner);
+
+            set_caller(owner);
+
+            // Check if proper error is returned
+            // While trying to pause a batch containing not owned asset
+            assert_eq!(
+                contract.pause_batch(Vec::from([first_id, foreign_id, last_id])),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+
+            // Check if proper error is returned
+            // While trying to pause the same asset twice
+            assert_eq!(
+                contract.pause_batch(Vec::from([first_id, last_id, first_id])),
+                Err(AssetCO2EmissionsError::AlreadyPaused)
+            );
+
+            // Check if no asset got paused
+            assert_eq!(contract.has_paused(first_id), Some(false));
+            assert_eq!(contract.has_paused(last_id), Some(false));
+        }
+
+        #[ink::test]
+        fn should_get_asset_return_none_for_inconsistent_storage() {
+            let (accounts, mut contract) = prepare_env();
+
+            let without_emissions = blast_default_asset(&mut contract, &accounts.django);
+            let without_parent = blast_default_asset(&mut contract, &accounts.django);
+            let complete = blast_default_asset(&mut contract, &accounts.django);
+
+            // Deliberately break storage of the first two assets
+            contract.co2_emissions.remove(without_emissions);
+            contract.parent.remove(without_parent);
+
+            // Check if partially written assets are reported as missing instead of trapping
+            assert!(contract.get_asset(without_emissions).is_none());
+            assert!(contract.get_asset(without_parent).is_none());
+
+            // Check if complete asset is still returned
+            assert!(contract.get_asset(complete).is_some());
+        }
+
+        #[ink::test]
+        fn should_common_ancestor_work_properly() {
+            let ((accounts, mut contract), (root_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+            assert!(contract.pause(root_id).is_ok());
+
+            // Split root asset into two branches
+            for _ in 0..2 {
+                assert!(contract
+                    .blast(
+                        asset_owner,
+                        default_metadata(),
+                        new_emissions(1),
+                        Some((root_id, 1))
+                    )
+                    .is_ok());
+            }
+            let left_id = root_id + 1;
+            let right_id = root_id + 2;
+            let left_leaf_id = blast_child_chain(&mut contract, &asset_owner, left_id, 2);
+            let right_leaf_id = blast_child_chain(&mut contract, &asset_owner, right_id, 1);
+
+            // Blast unrelated asset
+            let unrelated_id = blast_default_asset(&mut contract, &accounts.eve);
+
+            // Check shared-ancestor pair
+            assert_eq!(
+                contract.common_ancestor(left_leaf_id, right_leaf_id),
+                Some(root_id)
+            );
+
+            // Check pair where one asset is an ancestor of the other
+            assert_eq!(
+                contract.common_ancestor(left_id, left_leaf_id),
+                Some(left_id)
+            );
+            assert_eq!(
+                contract.common_ancestor(left_leaf_id, left_id),
+                Some(left_id)
+            );
+
+            // Check unrelated pair
+            assert!(contract
+                .common_ancestor(left_leaf_id, unrelated_id)
+                .is_none());
+
+            // Check nonexistent asset
+            assert!(contract.common_ancestor(left_leaf_id, 1000).is_none());
+        }
+
+        #[ink::test]
+        fn should_emission_event_have_category_topic() {
+            let (accounts, mut contract) = prepare_env();
+
+            let mut emissions = new_emissions(1);
+            emissions[0].category = EmissionsCategory::Transport;
+
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let topics = &emitted_events[1].topics;
+
+            // Check if Emission event has signature, id and category topics
+            assert_eq!(3, topics.len());
+
+            // Check if category topic is correctly hashed
+            let expected_topic = encoded_into_hash(&PrefixedValue {
+                prefix: b"InfinityAsset::Emission::category",
+                value: &EmissionsCategory::Transport,
+            });
+            assert_event_topics(Vec::from([expected_topic]), Vec::from([topics[2].clone()]));
+
+            // Check if category topic differs between categories
+            let other_topic = encoded_into_hash(&PrefixedValue {
+                prefix: b"InfinityAsset::Emission::category",
+                value: &EmissionsCategory::Process,
+            });
+            assert_ne!(expected_topic, other_topic);
+        }
+
+        #[ink::test]
+        fn should_inherited_vs_added_work_properly() {
+            let ((_accounts, mut contract), (root_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.inherited_vs_added(1000).is_none());
+
+            // Check if root asset inherits nothing
+            assert_eq!(contract.inherited_vs_added(root_id), Some((0, 1)));
+
+            set_caller(asset_owner);
+            assert!(contract.pause(root_id).is_ok());
+
+            let mut emissions = new_emissions(2);
+            emissions[0].value = 30;
+            emissions[1].value = 12;
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    emissions,
+                    Some((root_id, 1))
+                )
+                .is_ok());
+            let child_id = root_id + 1;
+            assert!(contract.pause(child_id).is_ok());
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    new_emissions(3),
+                    Some((child_id, 1))
+                )
+                .is_ok());
+            let grandchild_id = child_id + 1;
+
+            // Check child asset split
+            assert_eq!(contract.inherited_vs_added(child_id), Some((1, 42)));
+
+            // Check grandchild asset split: 1 + 2 + 3 added on the grandchild
+            assert_eq!(contract.inherited_vs_added(grandchild_id), Some((43, 6)));
+        }
+
+        #[ink::test]
+        fn should_distinct_data_source_count_work_properly() {
+            let ((_accounts, mut contract), (root_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.distinct_data_source_count(1000).is_none());
+
+            // Check lineage reusing a single data source
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, root_id, 3);
+            assert_eq!(contract.distinct_data_source_count(leaf_id), Some(1));
+
+            // Blast child with several distinct data sources, one of them reused
+            assert!(contract.pause(leaf_id).is_ok());
+            let mut emissions = new_emissions(3);
+            emissions[0].data_source = b"ERP export".to_vec();
+            emissions[1].data_source = b"Smart meter".to_vec();
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    emissions,
+                    Some((leaf_id, 1))
+                )
+                .is_ok());
+
+            // Check lineage with several distinct data sources
+            assert_eq!(
+                contract.distinct_data_source_count(contract.next_id - 1),
+                Some(3)
+            );
+        }
+
+        #[ink::test]
+        fn should_transfer_clear_pending_offer() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Offer the asset and then transfer it directly to another account
+            assert!(contract.offer(asset_id, accounts.bob).is_ok());
+            assert!(contract
+                .transfer(accounts.eve, asset_id, new_emissions(1))
+                .is_ok());
+
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+
+            // Check events count
+            // 1 * Blasted + 1 * Emission + 1 * Offered + 1 * Transfer + 1 * Emission + 1 * OfferRevoked
+            assert_eq!(1 + 1 + 1 + 1 + 1 + 1, emitted_events.len());
+            let decoded_event = <Event as scale::Decode>::decode(&mut &emitted_events[5].data[..])
+                .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::OfferRevoked(OfferRevoked { id }) if id == asset_id
+            ));
+
+            // Check if offer is cleared
+            assert!(!contract.offers.contains(asset_id));
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to claim an asset whose offer got cleared
+            assert_eq!(
+                contract.claim(asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::NotOffered)
+            );
+            assert_eq!(contract.owner_of(asset_id), Some(accounts.eve));
+        }
+
+        #[ink::test]
+        fn should_weighted_emission_date_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.weighted_emission_date(1000).is_none());
+
+            // Blast asset with all emissions sharing the same date
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), new_emissions(5), None)
+                .is_ok());
+
+            // Check if result equals the shared date
+            assert_eq!(
+                contract.weighted_emission_date(1),
+                Some(default_timestamp())
+            );
+
+            // Blast asset with weighted mix of dates
+            let mut emissions = new_emissions(2);
+            emissions[0].value = 3;
+            emissions[0].date = 1_000;
+            emissions[1].value = 1;
+            emissions[1].date = 2_001;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check weighted mean (3 * 1000 + 1 * 2001) / 4 = 1250.25, rounded down
+            assert_eq!(contract.weighted_emission_date(2), Some(1_250));
+        }
+
+        #[ink::test]
+        fn should_prefixed_value_handle_large_inputs() {
+            let prefix = vec![1u8; 1 << 20];
+            let value = vec![2u8; 1 << 20];
+            let prefixed = PrefixedValue {
+                prefix: &prefix,
+                value: &value,
+            };
+
+            // Check if size hint covers both parts without overflowing
+            let expected_size =
+                scale::Encode::size_hint(&prefix[..]) + scale::Encode::size_hint(&value);
+            assert_eq!(scale::Encode::size_hint(&prefixed), expected_size);
+
+            // Check if large input is hashed without panic
+            assert_ne!(encoded_into_hash(&prefixed), Hash::CLEAR_HASH);
+        }
+
+        #[ink::test]
+        fn should_emissions_matrix_work_properly() {
+            let ((_accounts, mut contract), (root_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.emissions_matrix(1000).is_none());
+
+            set_caller(asset_owner);
+            assert!(contract.pause(root_id).is_ok());
+
+            let mut emissions = new_emissions(4);
+            emissions[0].category = EmissionsCategory::Process;
+            emissions[0].balanced = false;
+            emissions[0].value = 10;
+            emissions[1].category = EmissionsCategory::Process;
+            emissions[1].value = 20;
+            emissions[2].category = EmissionsCategory::Process;
+            emissions[2].balanced = false;
+            emissions[2].value = 5;
+            emissions[3].category = EmissionsCategory::Upstream;
+            emissions[3].balanced = false;
+            emissions[3].value = 7;
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    emissions,
+                    Some((root_id, 1))
+                )
+                .is_ok());
+            let child_id = root_id + 1;
+
+            let matrix = contract.emissions_matrix(child_id).unwrap();
+
+            // Check matrix entries, the root asset contributes balanced upstream emissions
+            assert_eq!(
+                matrix,
+                Vec::from([
+                    (EmissionsCategory::Process, false, 15),
+                    (EmissionsCategory::Process, true, 20),
+                    (EmissionsCategory::Upstream, false, 7),
+                    (EmissionsCategory::Upstream, true, 1),
+                ])
+            );
+
+            // Check if matrix entries sum to the total emissions
+            let matrix_total: u128 = matrix.iter().map(|(_, _, total)| total).sum();
+            assert_eq!(matrix_total, contract.total_emissions(child_id));
+        }
+
+        #[ink::test]
+        fn should_reject_zero_relation_in_blast() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+            assert!(contract.pause(asset_id).is_ok());
+
+            // Check if proper error is returned
+            // While trying to blast a child asset with Zero relation to its parent
+            assert_eq!(
+                contract.blast(
+                    asset_owner,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((asset_id, 0))
+                ),
+                Err(AssetCO2EmissionsError::InvalidAssetRelation)
+            );
+        }
+
+        #[ink::test]
+        fn should_blast_child_with_relation() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+            assert!(contract.pause(asset_id).is_ok());
+
+            // Blast a child asset using 50 units of the parent's quantity
+            let parent: ParentDetails = Some((asset_id, 50));
+            assert!(contract
+                .blast(asset_owner, default_metadata(), new_emissions(1), parent)
+                .is_ok());
+            let child_id = asset_id + 1;
+
+            // Check if relation is read back
+            assert_eq!(contract.get_parent_details(child_id), Some(parent));
+            assert_eq!(contract.get_asset(child_id).unwrap().parent, parent);
+            assert_eq!(
+                contract
+                    .query_emissions(child_id)
+                    .unwrap()
+                    .iter()
+                    .map(|asset| asset.asset_id)
+                    .collect::<Vec<_>>(),
+                Vec::from([child_id, asset_id])
+            );
+        }
+
+        #[ink::test]
+        fn should_median_emission_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.median_emission(1000).is_none());
+
+            // Single record
+            let mut emissions = new_emissions(1);
+            emissions[0].value = 7;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+            assert_eq!(contract.median_emission(1), Some(7));
+
+            // Odd number of records
+            let mut emissions = new_emissions(3);
+            for (item, value) in emissions.iter_mut().zip([30, 10, 20]) {
+                item.value = value;
+            }
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+            assert_eq!(contract.median_emission(2), Some(20));
+
+            // Even number of records, lower-middle value is returned
+            let mut emissions = new_emissions(4);
+            for (item, value) in emissions.iter_mut().zip([40, 1, 100, 15]) {
+                item.value = value;
+            }
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+            assert_eq!(contract.median_emission(3), Some(15));
+        }
+
+        #[ink::test]
+        fn should_emissions_by_lot_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.emissions_by_lot(1000, 0).is_none());
+
+            let mut emissions = new_emissions(4);
+            emissions[1].lot_id = 7;
+            emissions[2].lot_id = 9;
+            emissions[3].lot_id = 7;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions.clone(), None)
+                .is_ok());
+            let emissions = normalized(emissions);
+
+            // Check if records are filtered by lot
+            assert_eq!(
+                contract.emissions_by_lot(1, 7),
+                Some(Vec::from([emissions[1].clone(), emissions[3].clone()]))
+            );
+            assert_eq!(
+                contract.emissions_by_lot(1, 9),
+                Some(Vec::from([emissions[2].clone()]))
+            );
+            // Check if records without lot belong to lot 0
+            assert_eq!(
+                contract.emissions_by_lot(1, 0),
+                Some(Vec::from([emissions[0].clone()]))
+            );
+            // Check unknown lot
+            assert_eq!(contract.emissions_by_lot(1, 42), Some(Vec::new()));
+        }
+
+        #[ink::test]
+        fn should_can_be_parent_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check nonexistent asset
+            assert!(!contract.can_be_parent(asset_owner, 1000));
+
+            // Check not paused asset
+            assert!(!contract.can_be_parent(asset_owner, asset_id));
+
+            set_caller(asset_owner);
+            assert!(contract.pause(asset_id).is_ok());
+
+            // Check not owner
+            assert!(!contract.can_be_parent(accounts.bob, asset_id));
+
+            // Check parent at the depth limit
+            contract.depth.insert(asset_id, &MAX_TREE_DEPTH);
+            assert!(!contract.can_be_parent(asset_owner, asset_id));
+            contract.depth.remove(asset_id);
+
+            // Check if all preconditions are met
+            assert!(contract.can_be_parent(asset_owner, asset_id));
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((asset_id, 1))
+                )
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn should_limit_children_by_parent_quantity() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if only the owner can declare the quantity
+            set_caller(accounts.eve);
+            assert_eq!(
+                contract.set_quantity(asset_id, 100),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+
+            set_caller(asset_owner);
+            assert!(contract.set_quantity(asset_id, 100).is_ok());
+            assert_eq!(contract.quantity_of(asset_id), Some(100));
+            assert!(contract.pause(asset_id).is_ok());
+
+            // Split parent up to its quantity
+            for relation in [60, 40] {
+                assert!(contract
+                    .blast(
+                        asset_owner,
+                        default_metadata(),
+                        new_emissions(1),
+                        Some((asset_id, relation))
+                    )
+                    .is_ok());
+            }
+            // Check if children's quantity is their relation
+            assert_eq!(contract.quantity_of(asset_id + 1), Some(60));
+            assert_eq!(contract.quantity_of(asset_id + 2), Some(40));
+
+            // Check if proper error is returned
+            // While trying to split more than parent's quantity
+            assert_eq!(
+                contract.blast(
+                    asset_owner,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((asset_id, 1))
+                ),
+                Err(AssetCO2EmissionsError::ParentQuantityExceeded)
+            );
+            // While trying to lower quantity below already used one
+            assert_eq!(
+                contract.set_quantity(asset_id, 99),
+                Err(AssetCO2EmissionsError::ParentQuantityExceeded)
+            );
+
+            // Split a child further past its own quantity
+            assert!(contract.pause(asset_id + 2).is_ok());
+            assert_eq!(
+                contract.blast(
+                    asset_owner,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((asset_id + 2, 41))
+                ),
+                Err(AssetCO2EmissionsError::ParentQuantityExceeded)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_inflating_child_quantity() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+            assert!(contract.set_quantity(asset_id, 100).is_ok());
+            assert!(contract.pause(asset_id).is_ok());
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((asset_id, 40))
+                )
+                .is_ok());
+            let child_id = contract.next_id - 1;
+
+            // Check if proper error is returned
+            // While trying to raise child's quantity above its relation to the parent
+            assert_eq!(
+                contract.set_quantity(child_id, 41),
+                Err(AssetCO2EmissionsError::ParentQuantityExceeded)
+            );
+            assert_eq!(contract.quantity_of(child_id), Some(40));
+
+            // Check if child's quantity can be lowered
+            assert!(contract.set_quantity(child_id, 30).is_ok());
+            assert_eq!(contract.quantity_of(child_id), Some(30));
+        }
+
+        #[ink::test]
+        fn should_add_emissions_with_evidence_hash() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            let mut emission_item = default_emission_item();
+            emission_item.evidence_hash = Some([7; 32]);
+
+            set_caller(asset_owner);
+            assert!(contract
+                .add_emissions(asset_id, emission_item.clone())
+                .is_ok());
+
+            // Check if evidence hash is emitted in event
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            assert_emissions_event(emitted_events.last().unwrap(), asset_id, &emission_item);
+
+            // Check if evidence hash is stored
+            assert_eq!(
+                contract
+                    .get_asset_emissions(asset_id)
+                    .unwrap()
+                    .last()
+                    .unwrap()
+                    .evidence_hash,
+                Some([7; 32])
+            );
+        }
+
+        #[ink::test]
+        fn should_set_emission_rate_limit_be_owner_only() {
+            let (accounts, mut contract) = prepare_env();
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to configure the rate limit as nonowner
+            assert_eq!(
+                contract.set_emission_rate_limit(1, 60),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
+        }
+
+        #[ink::test]
+        fn should_rate_limit_add_emissions() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(accounts.alice);
+            assert!(contract.set_emission_rate_limit(2, 60).is_ok());
+            let now = default_timestamp() * 1000;
+            test::set_block_timestamp::<Environment>(now);
+
+            // Append up to the quota
+            set_caller(asset_owner);
+            for _ in 0..2 {
+                assert!(contract
+                    .add_emissions(asset_id, default_emission_item())
+                    .is_ok());
+            }
+
+            // Check if proper error is returned
+            // While trying to exceed the quota within the window
+            test::set_block_timestamp::<Environment>(now + 59_999);
+            assert_eq!(
+                contract.add_emissions(asset_id, default_emission_item()),
+                Err(AssetCO2EmissionsError::EmissionRateLimited)
+            );
+
+            // Check if window rolls over
+            test::set_block_timestamp::<Environment>(now + 60_000);
+            assert!(contract
+                .add_emissions(asset_id, default_emission_item())
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn should_category_total_in_lineage_work_properly() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract
+                .category_total_in_lineage(1000, EmissionsCategory::Upstream)
+                .is_none());
+
+            // Build a tree: root (Upstream 1) <- child (Transport 5, Process 3) <- grandchild (Transport 7)
+            set_caller(asset_owner);
+            let mut child_emissions = new_emissions(2);
+            child_emissions[0].category = EmissionsCategory::Transport;
+            child_emissions[0].value = 5;
+            child_emissions[1].category = EmissionsCategory::Process;
+            child_emissions[1].value = 3;
+            assert!(contract.pause(asset_id).is_ok());
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    child_emissions,
+                    Some((asset_id, 1))
+                )
+                .is_ok());
+            let child_id = asset_id + 1;
+
+            let mut grandchild_emissions = new_emissions(1);
+            grandchild_emissions[0].category = EmissionsCategory::Transport;
+            grandchild_emissions[0].value = 7;
+            assert!(contract.pause(child_id).is_ok());
+            assert!(contract
+                .blast(
+                    asset_owner,
+                    default_metadata(),
+                    grandchild_emissions,
+                    Some((child_id, 1))
+                )
+                .is_ok());
+            let grandchild_id = child_id + 1;
+
+            // Check totals per category across the whole lineage
+            for (category, expected) in [
+                (EmissionsCategory::Transport, 12),
+                (EmissionsCategory::Process, 3),
+                (EmissionsCategory::Upstream, 1),
+            ] {
+                assert_eq!(
+                    contract.category_total_in_lineage(grandchild_id, category),
+                    Some(expected)
+                );
+            }
+            // Check that descendants are not included
+            assert_eq!(
+                contract.category_total_in_lineage(asset_id, EmissionsCategory::Transport),
+                Some(0)
+            );
+        }
+
+        #[ink::test]
+        fn should_export_emissions_csv_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.export_emissions_csv(1000).is_none());
+
+            let mut emissions = new_emissions(2);
+            emissions[0].value = 10;
+            emissions[1].category = EmissionsCategory::Transport;
+            emissions[1].balanced = false;
+            emissions[1].data_source = Vec::from([0xabu8, 0x0f]);
+            emissions[1].date = 1_600_000_000;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check if rows match the expected text
+            assert_eq!(
+                contract.export_emissions_csv(1),
+                Some(
+                    b"Upstream,10,1682632800,true,00010203\nTransport,2,1600000000,false,ab0f"
+                        .to_vec()
+                )
+            );
+        }
+
+        #[ink::test]
+        fn should_would_exceed_cap_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.would_exceed_cap(1000, 1).is_none());
+
+            assert!(contract
+                .blast(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(MAX_EMISSIONS_PER_ASSET - 1),
+                    None
+                )
+                .is_ok());
+
+            // Check values around the cap
+            assert_eq!(contract.would_exceed_cap(1, 0), Some(false));
+            assert_eq!(contract.would_exceed_cap(1, 1), Some(false));
+            assert_eq!(contract.would_exceed_cap(1, 2), Some(true));
+            assert_eq!(contract.would_exceed_cap(1, u32::MAX), Some(true));
+
+            // Check if the answer matches `add_emissions`
+            assert!(contract.add_emissions(1, default_emission_item()).is_ok());
+            assert_eq!(contract.would_exceed_cap(1, 1), Some(true));
+            assert_eq!(
+                contract.add_emissions(1, default_emission_item()),
+                Err(AssetCO2EmissionsError::EmissionsOverflow)
+            );
+        }
+
+        #[ink::test]
+        fn should_blast_deterministic_be_idempotent() {
+            let (accounts, mut contract) = prepare_env();
+
+            set_caller(accounts.bob);
+            let asset_id = contract
+                .blast_deterministic(accounts.bob, default_metadata(), new_emissions(1), None, 42)
+                .expect("First deterministic blast should succeed");
+
+            // Check if asset is created with the derived id
+            assert_eq!(contract.owner_of(asset_id), Some(accounts.bob));
+            assert_eq!(
+                contract.get_asset_emissions(asset_id),
+                Some(normalized(new_emissions(1)))
+            );
+
+            // Check if proper error is returned
+            // While replaying the same blast
+            assert_eq!(
+                contract.blast_deterministic(
+                    accounts.bob,
+                    default_metadata(),
+                    new_emissions(1),
+                    None,
+                    42,
+                ),
+                Err(AssetCO2EmissionsError::AssetAlreadyExists)
+            );
+            assert_eq!(contract.list_assets(accounts.bob), Vec::from([asset_id]));
+
+            // Check if other nonce or caller derive other ids
+            let other_nonce = contract
+                .blast_deterministic(accounts.bob, default_metadata(), new_emissions(1), None, 43)
+                .unwrap();
+            set_caller(accounts.charlie);
+            let other_caller = contract
+                .blast_deterministic(accounts.bob, default_metadata(), new_emissions(1), None, 42)
+                .unwrap();
+            assert_ne!(other_nonce, asset_id);
+            assert_ne!(other_caller, asset_id);
+            assert_ne!(other_caller, other_nonce);
+        }
+
+        #[ink::test]
+        fn should_top_emitters_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check empty contract
+            assert!(contract.top_emitters(10).is_empty());
+
+            // bob: 1 + 2 = 3, charlie: 10, django: 1
+            for (owner, value) in [
+                (accounts.bob, 1),
+                (accounts.charlie, 10),
+                (accounts.bob, 2),
+                (accounts.django, 1),
+            ] {
+                let mut emissions = new_emissions(1);
+                emissions[0].value = value;
+                assert!(contract
+                    .blast(owner, default_metadata(), emissions, None)
+                    .is_ok());
             }
+
+            // Check if owners are ranked descending
+            assert_eq!(
+                contract.top_emitters(10),
+                Vec::from([
+                    (accounts.charlie, 10),
+                    (accounts.bob, 3),
+                    (accounts.django, 1)
+                ])
+            );
+            // Check if limit is applied
+            assert_eq!(
+                contract.top_emitters(2),
+                Vec::from([(accounts.charlie, 10), (accounts.bob, 3)])
+            );
+            assert!(contract.top_emitters(0).is_empty());
+        }
+
+        #[ink::test]
+        fn should_owner_emissions_be_maintained() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            let own = |contract: &InfinityAsset, id: AssetId| {
+                contract
+                    .get_asset_emissions(id)
+                    .unwrap()
+                    .iter()
+                    .map(|item| item.value)
+                    .sum::<u128>()
+            };
+
+            // Check after mint
+            assert_eq!(
+                contract.get_owner_emissions(asset_owner),
+                own(&contract, asset_id)
+            );
+            assert_eq!(contract.get_owner_emissions(accounts.eve), 0);
+
+            // Check after adding emissions
+            set_caller(asset_owner);
+            assert!(contract
+                .add_emissions(asset_id, default_emission_item())
+                .is_ok());
+            let before_transfer = own(&contract, asset_id);
+            assert_eq!(contract.get_owner_emissions(asset_owner), before_transfer);
+
+            // Check after transfer: own emissions and transfer emissions go to the new owner
+            assert!(contract
+                .transfer(accounts.eve, asset_id, new_emissions(2))
+                .is_ok());
+            assert_eq!(contract.get_owner_emissions(asset_owner), 0);
+            assert_eq!(
+                contract.get_owner_emissions(accounts.eve),
+                before_transfer + 3
+            );
+
+            // Check if child's inherited emissions are not credited to its owner
+            set_caller(accounts.eve);
+            assert!(contract.pause(asset_id).is_ok());
+            assert!(contract
+                .blast(
+                    accounts.frank,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((asset_id, 1))
+                )
+                .is_ok());
+            assert_eq!(contract.get_owner_emissions(accounts.frank), 1);
+            assert_eq!(
+                contract.get_owner_emissions(accounts.eve),
+                before_transfer + 3
+            );
+        }
+
+        #[ink::test]
+        fn should_flattened_lineage_emissions_work_properly() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.flattened_lineage_emissions(1000).is_none());
+
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 2);
+            set_caller(asset_owner);
+            assert!(contract
+                .add_emissions(leaf_id, default_emission_item())
+                .is_ok());
+
+            // Check if each emission is tagged with its Asset id
+            let expected: Vec<(AssetId, CO2Emissions)> = [leaf_id, leaf_id - 1, asset_id]
+                .into_iter()
+                .flat_map(|id| {
+                    contract
+                        .get_asset_emissions(id)
+                        .unwrap()
+                        .into_iter()
+                        .map(move |item| (id, item))
+                })
+                .collect();
+            assert_eq!(expected.len(), 4);
+            assert_eq!(
+                contract.flattened_lineage_emissions(leaf_id),
+                Some(expected)
+            );
+        }
+
+        #[ink::test]
+        fn should_not_transfer_to_self() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Check if proper error is returned
+            // While trying to transfer an asset to its current owner
+            assert_eq!(
+                contract.transfer(asset_owner, asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::TransferToSelf)
+            );
+            assert_eq!(contract.transfer_count_of(asset_id), Some(0));
+        }
+
+        #[ink::test]
+        fn should_failed_transfer_leave_state_unchanged() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            let emissions_before = contract.get_asset_emissions(asset_id);
+            let emitted_events_before = test::recorded_events().count();
+
+            set_caller(asset_owner);
+
+            // Check if proper error is returned
+            // While trying to transfer with emissions exceeding the per-Asset cap
+            assert_eq!(
+                contract.transfer(
+                    accounts.eve,
+                    asset_id,
+                    new_emissions(MAX_EMISSIONS_PER_ASSET)
+                ),
+                Err(AssetCO2EmissionsError::EmissionsOverflow)
+            );
+
+            // Check if ownership and emissions are unchanged
+            assert_eq!(contract.owner_of(asset_id), Some(asset_owner));
+            assert_eq!(contract.list_assets(asset_owner), Vec::from([asset_id]));
+            assert!(contract.list_assets(accounts.eve).is_empty());
+            assert_eq!(contract.get_asset_emissions(asset_id), emissions_before);
+            assert_eq!(test::recorded_events().count(), emitted_events_before);
+        }
+
+        #[ink::test]
+        fn should_storage_presence_range_report_gaps() {
+            let ((accounts, mut contract), (asset_id, _asset_owner)) = env_with_default_asset();
+
+            // Simulate a blast that failed after taking an id
+            contract.next_id += 1;
+            let gap_id = asset_id + 1;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), new_emissions(1), None)
+                .is_ok());
+
+            // Check if the gap id reports false
+            assert_eq!(
+                contract.storage_presence_range(asset_id, 4),
+                Vec::from([
+                    (asset_id, true),
+                    (gap_id, false),
+                    (gap_id + 1, true),
+                    (gap_id + 2, false)
+                ])
+            );
+
+            // Check if range is capped
+            assert_eq!(
+                contract.storage_presence_range(0, u32::MAX).len(),
+                MAX_SCAN_LENGTH as usize
+            );
+            assert!(contract.storage_presence_range(AssetId::MAX, 5).is_empty());
+        }
+
+        #[ink::test]
+        fn should_set_category_weight_be_owner_only() {
+            let (accounts, mut contract) = prepare_env();
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to configure a weight as nonowner
+            assert_eq!(
+                contract.set_category_weight(EmissionsCategory::Upstream, 5_000),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
+            assert_eq!(
+                contract.category_weight(EmissionsCategory::Upstream),
+                DEFAULT_CATEGORY_WEIGHT
+            );
+        }
+
+        #[ink::test]
+        fn should_weighted_footprint_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.weighted_footprint(1000).is_none());
+
+            // Upstream 100, Process 30, Transport 7
+            let mut emissions = new_emissions(3);
+            emissions[0].value = 100;
+            emissions[1].category = EmissionsCategory::Process;
+            emissions[1].value = 30;
+            emissions[2].category = EmissionsCategory::Transport;
+            emissions[2].value = 7;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check if default weights keep the plain total
+            assert_eq!(contract.weighted_footprint(1), Some(137));
+
+            // Upstream 1.5x, Process 0.5x, Transport unchanged
+            assert!(contract
+                .set_category_weight(EmissionsCategory::Upstream, 15_000)
+                .is_ok());
+            assert!(contract
+                .set_category_weight(EmissionsCategory::Process, 5_000)
+                .is_ok());
+            assert_eq!(contract.weighted_footprint(1), Some(150 + 15 + 7));
+
+            // Check if weights apply across the lineage
+            assert!(contract.pause(1).is_ok());
+            assert!(contract
+                .blast(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((1, 1))
+                )
+                .is_ok());
+            // 101 Upstream * 1.5 rounded down
+            assert_eq!(contract.weighted_footprint(2), Some(151 + 15 + 7));
+        }
+
+        #[ink::test]
+        fn should_reject_next_id_regression() {
+            let ((accounts, mut contract), (asset_id, _asset_owner)) = env_with_default_asset();
+
+            // Simulate a faulty upgrade resetting the id counter
+            contract.next_id = 1;
+
+            // Check if proper error is returned
+            // While trying to blast with a regressed id
+            assert_eq!(
+                contract.blast(accounts.alice, default_metadata(), new_emissions(1), None),
+                Err(AssetCO2EmissionsError::IdRegression)
+            );
+            assert_eq!(contract.list_assets(accounts.alice), Vec::new());
+
+            // Check if restoring the counter allows blasting again
+            contract.next_id = asset_id + 1;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), new_emissions(1), None)
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn should_current_ownership_emissions_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.current_ownership_emissions(1000).is_none());
+
+            // Check never transferred asset
+            assert_eq!(
+                contract.current_ownership_emissions(asset_id),
+                Some(Vec::new())
+            );
+
+            set_caller(asset_owner);
+            let mut first_transfer = new_emissions(2);
+            first_transfer[0].value = 11;
+            assert!(contract
+                .transfer(accounts.eve, asset_id, first_transfer.clone())
+                .is_ok());
+
+            // Check freshly transferred asset
+            assert_eq!(
+                contract.current_ownership_emissions(asset_id),
+                Some(normalized(first_transfer.clone()))
+            );
+
+            // Check if later added emissions are not included
+            set_caller(accounts.eve);
+            assert!(contract
+                .add_emissions(asset_id, default_emission_item())
+                .is_ok());
+            assert_eq!(
+                contract.current_ownership_emissions(asset_id),
+                Some(normalized(first_transfer))
+            );
+
+            // Check if next transfer replaces the slice
+            let mut second_transfer = new_emissions(1);
+            second_transfer[0].value = 22;
+            assert!(contract
+                .transfer(accounts.frank, asset_id, second_transfer.clone())
+                .is_ok());
+            assert_eq!(
+                contract.current_ownership_emissions(asset_id),
+                Some(normalized(second_transfer))
+            );
+        }
+
+        #[ink::test]
+        fn should_emissions_missing_source_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.emissions_missing_source(1000).is_none());
+
+            let mut emissions = new_emissions(4);
+            emissions[1].data_source = Vec::new();
+            emissions[3].data_source = Vec::new();
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            // Check if unsourced records are found
+            assert_eq!(
+                contract.emissions_missing_source(1),
+                Some(Vec::from([1, 3]))
+            );
+
+            // Check fully sourced asset
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), new_emissions(2), None)
+                .is_ok());
+            assert_eq!(contract.emissions_missing_source(2), Some(Vec::new()));
+        }
+
+        #[ink::test]
+        fn should_set_require_data_source_be_owner_only() {
+            let (accounts, mut contract) = prepare_env();
+
+            set_caller(accounts.bob);
+
+            // Check if proper error is returned
+            // While trying to require Data Sources as nonowner
+            assert_eq!(
+                contract.set_require_data_source(true),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
+            assert!(!contract.require_data_source);
+        }
+
+        #[ink::test]
+        fn should_require_data_source_when_configured() {
+            let (accounts, mut contract) = prepare_env();
+
+            let mut emissions = new_emissions(1);
+            emissions[0].data_source = Vec::new();
+
+            // Check if empty Data Source is accepted by default
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions.clone(), None)
+                .is_ok());
+
+            assert!(contract.set_require_data_source(true).is_ok());
+
+            // Check if proper error is returned
+            // While trying to record an empty Data Source when it is required
+            assert_eq!(
+                contract.blast(accounts.alice, default_metadata(), emissions.clone(), None),
+                Err(AssetCO2EmissionsError::DataSourceRequired)
+            );
+            assert_eq!(
+                contract.add_emissions(1, emissions[0].clone()),
+                Err(AssetCO2EmissionsError::DataSourceRequired)
+            );
+            assert!(contract.add_emissions(1, default_emission_item()).is_ok());
+        }
+
+        #[ink::test]
+        fn should_not_blast_self_parent() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if proper error is returned
+            // While trying to use the id about to be assigned as the parent
+            let next_id = contract.next_id;
+            assert_eq!(
+                contract.blast(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((next_id, 1))
+                ),
+                Err(AssetCO2EmissionsError::SelfParent)
+            );
+            assert_eq!(contract.next_id, next_id);
+            assert!(contract.owner_of(next_id).is_none());
         }
 
         #[ink::test]
-        fn should_set_new_owner() {
-            let (accounts, mut contract) = prepare_env();
+        fn should_lineage_edges_work_properly() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            // Check if proper contract owner set
-            assert_eq!(contract.contract_owner, accounts.alice);
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.lineage_edges(1000).is_none());
 
-            // Set a new contract owner
-            assert!(contract.set_contract_owner(accounts.bob).is_ok());
+            // Check root asset
+            assert_eq!(contract.lineage_edges(asset_id), Some(Vec::new()));
 
-            // Check if new contract owner is properly set
-            assert_eq!(contract.contract_owner, accounts.bob);
+            // Check a simple chain
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 2);
+            assert_eq!(
+                contract.lineage_edges(leaf_id),
+                Some(Vec::from([(leaf_id, leaf_id - 1), (leaf_id - 1, asset_id)]))
+            );
         }
 
         #[ink::test]
-        fn should_reject_set_new_owner() {
+        fn should_set_blast_fee_be_owner_only() {
             let (accounts, mut contract) = prepare_env();
 
-            // Check initial contract owner
-            assert_eq!(contract.contract_owner, accounts.alice);
-
             set_caller(accounts.bob);
 
             // Check if proper error is returned
-            // While trying to set a new contract owner as nonowner
+            // While trying to set the blast fee as nonowner
             assert_eq!(
-                contract.set_contract_owner(accounts.bob),
+                contract.set_blast_fee(100),
                 Err(AssetCO2EmissionsError::NotContractOwner)
             );
-
-            // Check if contract owner remain unchanged
-            assert_eq!(contract.contract_owner, accounts.alice);
+            assert_eq!(contract.blast_fee(), 0);
         }
 
         #[ink::test]
-        #[should_panic(expected = "Only contract owner can set code hash")]
-        fn should_reject_set_code() {
+        fn should_blast_be_free_by_default() {
             let (accounts, mut contract) = prepare_env();
 
-            // Check initial contract owner
-            assert_eq!(contract.contract_owner, accounts.alice);
-
             set_caller(accounts.bob);
+            test::set_value_transferred::<DefaultEnvironment>(0);
 
-            // Check if panic
-            // While trying to set contract code as nonowner
-            contract.set_code([0x0; 32]);
+            // Check if blasting works without any payment
+            assert!(contract
+                .blast(accounts.bob, default_metadata(), new_emissions(1), None)
+                .is_ok());
         }
 
         #[ink::test]
-        fn should_reject_empty_emissions_during_blast() {
+        fn should_blast_fee_be_paid_to_contract_owner() {
             let (accounts, mut contract) = prepare_env();
+            assert!(contract.set_blast_fee(100).is_ok());
 
-            let metadata = default_metadata();
-            let parent = None;
-            let emissions: Vec<CO2Emissions> = Vec::new();
+            // Use a dedicated contract account, so its balance is independent of the owner's
+            let contract_account = accounts.frank;
+            test::set_callee::<DefaultEnvironment>(contract_account);
+            let owner_balance =
+                test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap();
+
+            set_caller(accounts.bob);
 
             // Check if proper error is returned
-            // While trying to blast asset without CO2 Emissions items
+            // While trying to blast with an underpayment
+            test::set_value_transferred::<DefaultEnvironment>(99);
             assert_eq!(
-                contract.blast(accounts.alice, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::EmissionsEmpty)
+                contract.blast(accounts.bob, default_metadata(), new_emissions(1), None),
+                Err(AssetCO2EmissionsError::InsufficientFee)
+            );
+            assert!(contract.list_assets(accounts.bob).is_empty());
+
+            // Check if the fee is transferred to the contract owner
+            test::set_account_balance::<DefaultEnvironment>(contract_account, 100);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            assert!(contract
+                .blast(accounts.bob, default_metadata(), new_emissions(1), None)
+                .is_ok());
+            assert_eq!(
+                test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap(),
+                owner_balance + 100
             );
         }
 
         #[ink::test]
-        fn should_reject_single_zero_emissions_item_during_blast() {
+        fn should_withdraw_work_properly() {
             let (accounts, mut contract) = prepare_env();
 
-            let metadata = default_metadata();
-            let parent = None;
+            // Use a dedicated contract account, so its balance is independent of the owner's
+            let contract_account = accounts.frank;
+            test::set_callee::<DefaultEnvironment>(contract_account);
+            test::set_account_balance::<DefaultEnvironment>(contract_account, 500);
+            let owner_balance =
+                test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap();
 
-            let mut item = default_emission_item();
-            item.value = 0;
+            // Check if proper error is returned
+            // While trying to withdraw as nonowner
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.withdraw(100),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
 
-            let mut emissions: Vec<CO2Emissions> = new_emissions(1);
-            emissions[0].value = 0;
+            set_caller(accounts.alice);
+            assert!(contract.withdraw(200).is_ok());
+
+            // Check balances
+            assert_eq!(
+                test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap(),
+                owner_balance + 200
+            );
+            assert_eq!(
+                test::get_account_balance::<DefaultEnvironment>(contract_account).unwrap(),
+                300
+            );
+
+            // Check emitted event
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let decoded_event =
+                <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                    .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::Withdrawn(Withdrawn { to, amount }) if to == accounts.alice && amount == 200
+            ));
 
             // Check if proper error is returned
-            // While trying to blast asset with Zero emissions item
+            // While trying to withdraw more than the contract balance
             assert_eq!(
-                contract.blast(accounts.alice, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::ZeroEmissionsItem)
+                contract.withdraw(301),
+                Err(AssetCO2EmissionsError::InsufficientBalance)
             );
         }
 
         #[ink::test]
-        fn should_reject_zero_emissions_item_in_array_during_blast() {
+        fn should_creation_emissions_work_properly() {
             let (accounts, mut contract) = prepare_env();
 
-            let metadata = default_metadata();
-            let parent = None;
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.creation_emissions(1000).is_none());
+            assert!(contract.post_creation_emissions(1000).is_none());
 
-            let mut emissions = new_emissions(3);
-            emissions[1].value = 0;
+            let emissions = new_emissions(2);
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions.clone(), None)
+                .is_ok());
+            let emissions = normalized(emissions);
 
-            // Check if proper error is returned
-            // While trying to blast asset with a Zero emissions item in many items
+            // Check freshly blasted asset
+            assert_eq!(contract.creation_emissions(1), Some(emissions.clone()));
+            assert_eq!(contract.post_creation_emissions(1), Some(Vec::new()));
+
+            // Check after adding a third emission
+            let mut added = default_emission_item();
+            added.value = 42;
+            assert!(contract.add_emissions(1, added.clone()).is_ok());
+            assert_eq!(contract.creation_emissions(1), Some(emissions));
             assert_eq!(
-                contract.blast(accounts.alice, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::ZeroEmissionsItem)
+                contract.post_creation_emissions(1),
+                Some(normalized(Vec::from([added])))
             );
         }
 
         #[ink::test]
-        fn should_blast_with_single_emissions_item() {
+        fn should_max_emission_in_lineage_work_properly() {
             let (accounts, mut contract) = prepare_env();
 
-            let metadata = default_metadata();
-            let parent = None;
-
-            let emissions: Vec<CO2Emissions> = new_emissions(1);
-
-            let owner = accounts.bob;
-
-            // Check if asset is blasted
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.max_emission_in_lineage(1000).is_none());
+
+            // Root holds the heaviest emissions, the later one with the same value first
+            let mut root_emissions = new_emissions(3);
+            root_emissions[0].value = 50;
+            root_emissions[0].date = default_timestamp() + 1;
+            root_emissions[1].value = 50;
+            root_emissions[2].value = 10;
             assert!(contract
-                .blast(owner, metadata.clone(), emissions.clone(), parent)
+                .blast(
+                    accounts.alice,
+                    default_metadata(),
+                    root_emissions.clone(),
+                    None
+                )
                 .is_ok());
 
-            let expected_asset_id = 1;
+            assert!(contract.pause(1).is_ok());
+            let mut child_emissions = new_emissions(1);
+            child_emissions[0].value = 20;
+            assert!(contract
+                .blast(
+                    accounts.alice,
+                    default_metadata(),
+                    child_emissions.clone(),
+                    Some((1, 1))
+                )
+                .is_ok());
 
-            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            // Check if the ancestor's record with the earliest date is returned
+            assert_eq!(
+                contract.max_emission_in_lineage(2),
+                Some(InfinityAsset::normalize_offset(root_emissions[1].clone()))
+            );
+        }
 
-            // Check events count
-            // 1 * Blasted + 1 * Emission
-            assert_eq!(1 + 1, emitted_events.len());
+        #[ink::test]
+        fn should_owner_of_batch_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            let other_id = blast_default_asset(&mut contract, &accounts.eve);
 
-            // Check Blasted event
-            assert_blasted_event(
-                &emitted_events[0],
-                expected_asset_id,
-                metadata,
-                owner,
-                parent,
+            // Check if existing and missing ids are answered in order
+            assert_eq!(
+                contract.owner_of_batch(Vec::from([other_id, 1000, asset_id, other_id])),
+                Ok(Vec::from([
+                    Some(accounts.eve),
+                    None,
+                    Some(asset_owner),
+                    Some(accounts.eve)
+                ]))
             );
+            assert_eq!(contract.owner_of_batch(Vec::new()), Ok(Vec::new()));
 
-            // Check Emission event
-            assert_emissions_event(
-                &emitted_events[1],
-                expected_asset_id,
-                emissions[0].category,
-                emissions[0].data_source.clone(),
-                emissions[0].balanced,
-                emissions[0].date,
-                emissions[0].value,
+            // Check if the maximum number of ids is answered
+            let ids = Vec::from([asset_id; MAX_LIST_RETURN as usize]);
+            assert_eq!(
+                contract.owner_of_batch(ids).map(|owners| owners.len()),
+                Ok(MAX_LIST_RETURN as usize)
             );
         }
 
         #[ink::test]
-        fn should_blast_with_multiple_emissions_items() {
-            let (accounts, mut contract) = prepare_env();
+        fn should_reject_owner_of_batch_too_large() {
+            let ((_accounts, contract), (asset_id, _asset_owner)) = env_with_default_asset();
 
-            let metadata = default_metadata();
-            let parent = None;
+            // Check if proper error is returned
+            // While trying to query more than the maximum number of ids
+            let ids = Vec::from([asset_id; MAX_LIST_RETURN as usize + 1]);
+            assert_eq!(
+                contract.owner_of_batch(ids),
+                Err(AssetCO2EmissionsError::BatchTooLarge)
+            );
+        }
 
-            let emissions_items_count: u8 = 3;
-            let emissions = new_emissions(emissions_items_count);
+        #[ink::test]
+        fn should_set_asset_tags_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            let owner = accounts.eve;
+            // Check untagged asset
+            assert!(contract.get_asset_tags(asset_id).is_none());
 
-            // Check if asset is blasted
+            // Check if proper error is returned
+            // While trying to tag an asset as nonowner
+            set_caller(accounts.eve);
+            assert_eq!(
+                contract.set_asset_tags(asset_id, b"steel".to_vec()),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+
+            // Check setting and overwriting tags
+            set_caller(asset_owner);
+            assert!(contract.set_asset_tags(asset_id, b"steel".to_vec()).is_ok());
+            assert_eq!(contract.get_asset_tags(asset_id), Some(b"steel".to_vec()));
             assert!(contract
-                .blast(owner, metadata.clone(), emissions.clone(), parent)
+                .set_asset_tags(asset_id, b"steel,coil".to_vec())
                 .is_ok());
+            assert_eq!(
+                contract.get_asset_tags(asset_id),
+                Some(b"steel,coil".to_vec())
+            );
 
-            let expected_asset_id = 1;
-
+            // Check emitted event
             let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let decoded_event =
+                <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                    .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::AssetTagged(AssetTagged { id, tags }) if id == asset_id && tags == b"steel,coil".to_vec()
+            ));
 
-            // Check proper events count
-            // 1 * Blasted + 3 * Emission
+            // Check if proper error is returned
+            // While trying to exceed the tags length
+            assert!(contract
+                .set_asset_tags(asset_id, Vec::from([1u8; MAX_TAGS_LENGTH as usize]))
+                .is_ok());
             assert_eq!(
-                1 + emissions_items_count,
-                test::recorded_events().count() as u8
+                contract.set_asset_tags(asset_id, Vec::from([1u8; MAX_TAGS_LENGTH as usize + 1])),
+                Err(AssetCO2EmissionsError::TagsOverflow)
             );
+        }
 
-            // Check Blasted event for the asset
-            assert_blasted_event(
-                &emitted_events[0],
-                expected_asset_id,
-                metadata,
-                owner,
-                parent,
-            );
+        #[ink::test]
+        fn should_assets_by_tag_work_properly() {
+            let (accounts, mut contract) = prepare_env();
 
-            // Check Emission event for each emission item
-            for i in 0..(emissions_items_count as usize) {
-                assert_emissions_event(
-                    &emitted_events[i + 1],
-                    expected_asset_id,
-                    emissions[i].category,
-                    emissions[i].data_source.clone(),
-                    emissions[i].balanced,
-                    emissions[i].date,
-                    emissions[i].value,
-                );
+            // 1: "steel,coil", 2: untagged, 3: "aluminium", 4: "steel"
+            for _ in 0..4 {
+                blast_default_asset(&mut contract, &accounts.alice);
+            }
+            set_caller(accounts.alice);
+            for (id, tags) in [
+                (1, b"steel,coil".to_vec()),
+                (3, b"aluminium".to_vec()),
+                (4, b"steel".to_vec()),
+            ] {
+                assert!(contract.set_asset_tags(id, tags).is_ok());
             }
+
+            // Check substring matching
+            assert_eq!(
+                contract.assets_by_tag(b"steel".to_vec(), 0, 10),
+                Vec::from([1, 4])
+            );
+            assert_eq!(
+                contract.assets_by_tag(b"coil".to_vec(), 0, 10),
+                Vec::from([1])
+            );
+            assert!(contract.assets_by_tag(b"copper".to_vec(), 0, 10).is_empty());
+            // Check if empty tag matches all tagged assets
+            assert_eq!(
+                contract.assets_by_tag(Vec::new(), 0, 10),
+                Vec::from([1, 3, 4])
+            );
+
+            // Check pagination
+            assert_eq!(
+                contract.assets_by_tag(b"steel".to_vec(), 0, 2),
+                Vec::from([1])
+            );
+            assert_eq!(
+                contract.assets_by_tag(b"steel".to_vec(), 2, 2),
+                Vec::from([4])
+            );
         }
 
         #[ink::test]
-        fn should_nonexistent_get_emissions_work_properly() {
-            let contract = InfinityAsset::new();
+        fn should_get_asset_emissions_sorted_work_properly() {
+            let (accounts, mut contract) = prepare_env();
 
             // Check if contract return proper value for nonexistent asset
-            assert!(contract.get_asset_emissions(1000).is_none());
+            assert!(contract.get_asset_emissions_sorted(1000).is_none());
+
+            let mut emissions = new_emissions(4);
+            emissions[0].date = 300;
+            emissions[1].date = 100;
+            emissions[2].date = 200;
+            emissions[3].date = 100;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions.clone(), None)
+                .is_ok());
+
+            // Check if items are sorted by date, stable for equal dates
+            assert_eq!(
+                contract.get_asset_emissions_sorted(1),
+                Some(normalized(Vec::from([
+                    emissions[1].clone(),
+                    emissions[3].clone(),
+                    emissions[2].clone(),
+                    emissions[0].clone()
+                ])))
+            );
+            // Check if storage order is unchanged
+            assert_eq!(contract.get_asset_emissions(1), Some(normalized(emissions)));
         }
 
         #[ink::test]
-        fn should_get_emissions_work_properly() {
+        fn should_seal_contract_be_owner_only() {
             let (accounts, mut contract) = prepare_env();
 
-            let metadata = default_metadata();
-            let parent = None;
+            set_caller(accounts.bob);
 
-            let emissions = new_emissions(3);
+            // Check if proper error is returned
+            // While trying to seal the contract as nonowner
+            assert_eq!(
+                contract.seal_contract(),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
+            assert!(!contract.is_minting_sealed());
+        }
 
-            let asset_id = 1;
+        #[ink::test]
+        fn should_sealed_contract_reject_minting_only() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            // Check if asset is blasted
+            set_caller(accounts.alice);
+            assert!(contract.seal_contract().is_ok());
+            assert!(contract.is_minting_sealed());
+
+            // Check if proper error is returned
+            // While trying to mint after sealing
+            assert_eq!(
+                contract.blast(accounts.alice, default_metadata(), new_emissions(1), None),
+                Err(AssetCO2EmissionsError::MintingSealed)
+            );
+            assert_eq!(
+                contract.blast_deterministic(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(1),
+                    None,
+                    1
+                ),
+                Err(AssetCO2EmissionsError::MintingSealed)
+            );
+
+            // Check if transfers still work
+            set_caller(asset_owner);
             assert!(contract
-                .blast(accounts.eve, metadata, emissions.clone(), parent)
+                .transfer(accounts.eve, asset_id, new_emissions(1))
                 .is_ok());
+            assert_eq!(contract.owner_of(asset_id), Some(accounts.eve));
+        }
 
-            let emissions_from_state = contract.get_asset_emissions(asset_id);
+        #[ink::test]
+        fn should_category_percentages_work_properly() {
+            let (accounts, mut contract) = prepare_env();
 
-            // Check if contract return proper emissions data
-            assert!(emissions_from_state.is_some());
-            assert!(emissions.iter().eq(emissions_from_state.unwrap().iter()));
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.category_percentages(1000).is_none());
+
+            // Upstream 1, Transport 1, Process 1 => 3333 each
+            let mut emissions = new_emissions(3);
+            emissions[0].value = 1;
+            emissions[1].category = EmissionsCategory::Transport;
+            emissions[1].value = 1;
+            emissions[2].category = EmissionsCategory::Process;
+            emissions[2].value = 1;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+
+            let shares = contract.category_percentages(1).unwrap();
+            assert_eq!(
+                shares,
+                Vec::from([
+                    (EmissionsCategory::Process, 3333),
+                    (EmissionsCategory::Transport, 3333),
+                    (EmissionsCategory::Upstream, 3333)
+                ])
+            );
+            // Check if shares sum within rounding tolerance
+            let sum: u32 = shares.iter().map(|(_, share)| share).sum();
+            assert!((10_000 - shares.len() as u32..=10_000).contains(&sum));
+
+            // Check if shares are computed across the lineage, missing categories skipped
+            let mut emissions = new_emissions(1);
+            emissions[0].value = 3;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+            assert!(contract.pause(2).is_ok());
+            let mut emissions = new_emissions(1);
+            emissions[0].category = EmissionsCategory::Transport;
+            emissions[0].value = 1;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, Some((2, 1)))
+                .is_ok());
+            assert_eq!(
+                contract.category_percentages(3),
+                Some(Vec::from([
+                    (EmissionsCategory::Transport, 2500),
+                    (EmissionsCategory::Upstream, 7500)
+                ]))
+            );
         }
 
         #[ink::test]
-        fn should_nonexistent_get_metadata_work_properly() {
-            let contract = InfinityAsset::new();
+        fn should_owned_assets_range_work_properly() {
+            let (accounts, mut contract) = prepare_env();
 
-            // Check if contract return proper value for nonexistent asset
-            assert!(contract.get_metadata(1000).is_none());
+            // alice: 1, 2, 4, 5; bob: 3
+            for owner in [
+                accounts.alice,
+                accounts.alice,
+                accounts.bob,
+                accounts.alice,
+                accounts.alice,
+            ] {
+                blast_default_asset(&mut contract, &owner);
+            }
+
+            // Check a range covering part of the holdings
+            assert_eq!(
+                contract.owned_assets_range(accounts.alice, 2, 4),
+                Vec::from([2, 4])
+            );
+            assert_eq!(
+                contract.owned_assets_range(accounts.alice, 0, AssetId::MAX),
+                Vec::from([1, 2, 4, 5])
+            );
+            assert_eq!(
+                contract.owned_assets_range(accounts.bob, 1, 3),
+                Vec::from([3])
+            );
+
+            // Check empty results
+            assert!(contract.owned_assets_range(accounts.alice, 3, 3).is_empty());
+            assert!(contract.owned_assets_range(accounts.alice, 4, 2).is_empty());
+            assert!(contract.owned_assets_range(accounts.eve, 1, 5).is_empty());
         }
 
         #[ink::test]
-        fn should_get_metadata_work_properly() {
-            let ((_accounts, contract), (asset_id, _asset_owner)) = env_with_default_asset();
+        fn should_find_orphans_and_query_them_safely() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 2);
+            let middle_id = leaf_id - 1;
+
+            // Check there are no orphans
+            assert!(contract.find_orphans(0, 10).is_empty());
+
+            // Simulate force-removal of the middle asset
+            contract.asset_owner.remove(middle_id);
+            contract.metadata.remove(middle_id);
+            contract.co2_emissions.remove(middle_id);
+            contract.paused.remove(middle_id);
+            contract.parent.remove(middle_id);
+
+            // Check if orphan is detected
+            assert_eq!(contract.find_orphans(0, 10), Vec::from([leaf_id]));
+            assert!(contract.find_orphans(leaf_id + 1, 10).is_empty());
+
+            // Check if traversal stops at the missing parent
+            let tree = contract.query_emissions(leaf_id).unwrap();
+            assert_eq!(tree.len(), 1);
+            assert_eq!(tree[0].asset_id, leaf_id);
+        }
+
+        #[ink::test]
+        fn should_record_partial_offsets() {
+            let (accounts, mut contract) = prepare_env();
 
-            let metadata = default_metadata();
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.net_unoffset_total(1000).is_none());
 
-            let metadata_from_state = contract.get_metadata(asset_id);
+            // 60% offset, fully offset, legacy unbalanced and legacy balanced items
+            let mut emissions = new_emissions(4);
+            emissions[0].value = 100;
+            emissions[0].offset_value = 60;
+            emissions[1].value = 50;
+            emissions[1].offset_value = 50;
+            emissions[1].balanced = false;
+            emissions[2].value = 7;
+            emissions[2].balanced = false;
+            emissions[3].value = 9;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
 
-            // Check if contract return proper metadata
-            assert!(metadata_from_state.is_some());
-            assert!(metadata.iter().eq(metadata_from_state.unwrap().iter()));
+            // Check if balanced flag is derived from the offset
+            let stored = contract.get_asset_emissions(1).unwrap();
+            assert!(!stored[0].balanced);
+            assert!(stored[1].balanced);
+            assert!(!stored[2].balanced);
+            assert!(stored[3].balanced);
+
+            // Check if net total sums the not offset parts
+            assert_eq!(contract.net_unoffset_total(1), Some(40 + 7));
+            assert_eq!(contract.total_balanced_emissions(), 60 + 50 + 9);
+
+            // Check if net total is summed across the lineage
+            assert!(contract.pause(1).is_ok());
+            let mut emissions = new_emissions(1);
+            emissions[0].value = 10;
+            emissions[0].offset_value = 1;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, Some((1, 1)))
+                .is_ok());
+            assert_eq!(contract.net_unoffset_total(2), Some(40 + 7 + 9));
         }
 
         #[ink::test]
-        fn should_nonexistent_get_parent_work_properly() {
-            let contract = InfinityAsset::new();
+        fn should_reject_offset_exceeding_value() {
+            let (accounts, mut contract) = prepare_env();
 
-            // Check if contract return proper value for nonexistent asset
-            assert!(contract.get_parent_details(1000).is_none());
+            let mut emissions = new_emissions(1);
+            emissions[0].value = 10;
+            emissions[0].offset_value = 11;
+
+            // Check if proper error is returned
+            // While trying to offset more than the emission value
+            assert_eq!(
+                contract.blast(accounts.alice, default_metadata(), emissions, None),
+                Err(AssetCO2EmissionsError::OffsetExceedsValue)
+            );
         }
 
         #[ink::test]
-        fn should_get_parent_for_root_asset_work_properly() {
-            let ((_accounts, contract), (asset_id, _asset_owner)) = env_with_default_asset();
+        fn should_balanced_item_without_offset_be_fully_offset() {
+            let (accounts, mut contract) = prepare_env();
 
-            let parent = None;
+            let mut emissions = new_emissions(2);
+            emissions[0].value = 10;
+            emissions[0].offset_value = 0;
+            emissions[1].value = 5;
+            emissions[1].balanced = false;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
 
-            let parent_from_state = contract.get_parent_details(asset_id);
+            // Check if balanced item is stored fully offset
+            let stored = contract.get_asset_emissions(1).unwrap();
+            assert!(stored[0].balanced);
+            assert_eq!(stored[0].offset_value, 10);
 
-            // Check if contract return proper asset's parent
-            assert!(parent_from_state.is_some());
-            assert_eq!(parent, parent_from_state.unwrap());
+            // Check if both totals agree on the offset part
+            assert_eq!(contract.total_balanced_emissions(), 10);
+            assert_eq!(contract.net_unoffset_total(1), Some(5));
+            assert_eq!(contract.portfolio_stats(accounts.alice).balanced, 10);
         }
 
         #[ink::test]
-        fn should_owner_of_work_properly() {
-            let ((_accounts, contract), (asset_id, asset_owner)) = env_with_default_asset();
+        fn should_move_emission_work_properly() {
+            let (accounts, mut contract) = prepare_env();
 
-            let owner_from_state = contract.owner_of(asset_id);
+            let from_emissions = new_emissions(3);
+            assert!(contract
+                .blast(
+                    accounts.alice,
+                    default_metadata(),
+                    from_emissions.clone(),
+                    None
+                )
+                .is_ok());
+            let to_emissions = new_emissions(1);
+            assert!(contract
+                .blast(
+                    accounts.alice,
+                    default_metadata(),
+                    to_emissions.clone(),
+                    None
+                )
+                .is_ok());
 
-            // Check if contract return proper asset's owner
-            assert!(owner_from_state.is_some());
-            assert_eq!(asset_owner, owner_from_state.unwrap());
+            // Check if proper error is returned
+            // While trying to move a nonexistent item
+            assert_eq!(
+                contract.move_emission(1, 3, 2),
+                Err(AssetCO2EmissionsError::EmissionNotFound)
+            );
+
+            assert!(contract.move_emission(1, 1, 2).is_ok());
+            let from_emissions = normalized(from_emissions);
+            let to_emissions = normalized(to_emissions);
+
+            // Check if item is moved
+            assert_eq!(
+                contract.get_asset_emissions(1),
+                Some(Vec::from([
+                    from_emissions[0].clone(),
+                    from_emissions[2].clone()
+                ]))
+            );
+            assert_eq!(
+                contract.get_asset_emissions(2),
+                Some(Vec::from([
+                    to_emissions[0].clone(),
+                    from_emissions[1].clone()
+                ]))
+            );
+            assert_eq!(contract.creation_emissions(1).unwrap().len(), 2);
+
+            // Check emitted event
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let decoded_event =
+                <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                    .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::EmissionMoved(EmissionMoved { from: 1, to: 2, index: 1, emission })
+                    if emission == from_emissions[1]
+            ));
+
+            // Check if only the owner of both assets can move items
+            blast_default_asset(&mut contract, &accounts.bob);
+            assert_eq!(
+                contract.move_emission(1, 0, 3),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
         }
 
         #[ink::test]
-        fn should_already_blasted_asset_not_be_paused() {
-            let ((_accounts, contract), (asset_id, _asset_owner)) = env_with_default_asset();
+        fn should_not_move_emission_to_full_asset() {
+            let (accounts, mut contract) = prepare_env();
 
-            let paused = contract.has_paused(asset_id);
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), new_emissions(2), None)
+                .is_ok());
+            assert!(contract
+                .blast(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(MAX_EMISSIONS_PER_ASSET),
+                    None
+                )
+                .is_ok());
 
-            // Check if asset is not paused
-            assert!(paused.is_some());
-            assert!(!paused.unwrap());
+            // Check if proper error is returned
+            // While trying to move an item to an asset at its cap
+            assert_eq!(
+                contract.move_emission(1, 0, 2),
+                Err(AssetCO2EmissionsError::EmissionsOverflow)
+            );
+            assert_eq!(
+                contract.get_asset_emissions(1),
+                Some(normalized(new_emissions(2)))
+            );
         }
 
         #[ink::test]
-        fn should_not_owner_not_be_able_to_set_paused_state() {
-            let ((accounts, mut contract), (asset_id, _asset_owner)) = env_with_default_asset();
+        fn should_not_move_only_emission() {
+            let (accounts, mut contract) = prepare_env();
 
-            set_caller(accounts.eve);
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), new_emissions(1), None)
+                .is_ok());
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), new_emissions(1), None)
+                .is_ok());
 
             // Check if proper error is returned
-            // While trying to pause an asset as nonowner
+            // While trying to move the only item of an asset
             assert_eq!(
-                contract.pause(asset_id),
-                Err(AssetCO2EmissionsError::NotOwner)
+                contract.move_emission(1, 0, 2),
+                Err(AssetCO2EmissionsError::EmissionsEmpty)
+            );
+            assert_eq!(
+                contract.get_asset_emissions(1),
+                Some(normalized(new_emissions(1)))
+            );
+            assert_eq!(
+                contract.get_asset_emissions(2),
+                Some(normalized(new_emissions(1)))
             );
         }
 
         #[ink::test]
-        fn should_owner_be_able_to_set_paused_state() {
-            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+        fn should_unpause_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
             set_caller(asset_owner);
-
-            // Check if `pause` work
             assert!(contract.pause(asset_id).is_ok());
+            assert!(contract.unpause(asset_id).is_ok());
+
+            // Check if asset is not paused
+            assert_eq!(contract.has_paused(asset_id), Some(false));
 
+            // Check emitted event
             let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            assert_unpaused_event(emitted_events.last().unwrap(), asset_id);
 
-            // Check events count
-            // 1* Blasted + 1 * Emission + 1 * Paused
-            assert_eq!(1 + 1 + 1, test::recorded_events().count());
+            // Check if asset can be transferred again
+            assert!(contract
+                .transfer(accounts.eve, asset_id, new_emissions(1))
+                .is_ok());
+        }
 
-            // Check Paused event
-            assert_paused_event(&emitted_events[2], asset_id);
+        #[ink::test]
+        fn should_not_unpause_not_paused_asset() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
+
+            // Check if proper error is returned
+            // While trying to unpause not paused asset
+            assert_eq!(
+                contract.unpause(asset_id),
+                Err(AssetCO2EmissionsError::NotPaused)
+            );
+            // While trying to unpause nonexistent asset
+            assert_eq!(
+                contract.unpause(1000),
+                Err(AssetCO2EmissionsError::AssetNotFound)
+            );
         }
 
         #[ink::test]
-        fn should_owner_not_be_able_to_set_paused_state_while_already_paused() {
+        fn should_not_unpause_split_asset() {
             let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            blast_child_chain(&mut contract, &asset_owner, asset_id, 1);
 
+            // Check if proper error is returned
+            // While trying to unpause asset already split into children
             set_caller(asset_owner);
+            assert_eq!(
+                contract.unpause(asset_id),
+                Err(AssetCO2EmissionsError::HasChildren)
+            );
+            assert_eq!(contract.has_paused(asset_id), Some(true));
+        }
 
-            // Pause the asset
+        #[ink::test]
+        fn should_not_owner_not_be_able_to_unpause() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            set_caller(asset_owner);
             assert!(contract.pause(asset_id).is_ok());
 
             // Check if proper error is returned
-            // While trying to pause already paused asset
+            // While trying to unpause asset as nonowner
+            set_caller(accounts.eve);
             assert_eq!(
-                contract.pause(asset_id),
-                Err(AssetCO2EmissionsError::AlreadyPaused)
+                contract.unpause(asset_id),
+                Err(AssetCO2EmissionsError::NotOwner)
             );
+            assert_eq!(contract.has_paused(asset_id), Some(true));
         }
 
         #[ink::test]
-        fn should_reject_non_existent_parent_in_blast() {
+        fn should_total_balanced_emissions_work_properly() {
             let (accounts, mut contract) = prepare_env();
 
-            let metadata = default_metadata();
+            // Check empty contract
+            assert_eq!(contract.total_balanced_emissions(), 0);
 
-            let emissions: Vec<CO2Emissions> = new_emissions(1);
+            // Balanced 10, unbalanced 5
+            let mut emissions = new_emissions(2);
+            emissions[0].value = 10;
+            emissions[1].value = 5;
+            emissions[1].balanced = false;
+            assert!(contract
+                .blast(accounts.alice, default_metadata(), emissions, None)
+                .is_ok());
+            assert_eq!(contract.total_balanced_emissions(), 10);
 
-            let owner = accounts.alice;
+            // Unbalanced only on another asset
+            let mut emissions = new_emissions(1);
+            emissions[0].value = 7;
+            emissions[0].balanced = false;
+            assert!(contract
+                .blast(accounts.bob, default_metadata(), emissions, None)
+                .is_ok());
+            assert_eq!(contract.total_balanced_emissions(), 10);
 
-            let parent: ParentDetails = Some(1000);
+            // Balanced item added to the first asset, partial offset on the second
+            let mut item = default_emission_item();
+            item.value = 3;
+            assert!(contract.add_emissions(1, item).is_ok());
+            set_caller(accounts.bob);
+            let mut item = default_emission_item();
+            item.value = 4;
+            item.offset_value = 1;
+            assert!(contract.add_emissions(2, item).is_ok());
+            assert_eq!(contract.total_balanced_emissions(), 10 + 3 + 1);
+        }
 
-            set_caller(owner);
+        #[ink::test]
+        fn should_retire_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
             // Check if proper error is returned
-            // While trying to blast asset with nonexistent parent
+            // While trying to retire an asset as nonowner
+            set_caller(accounts.eve);
             assert_eq!(
-                contract.blast(owner, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::AssetNotFound)
+                contract.retire(asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::NotOwner)
+            );
+
+            set_caller(asset_owner);
+            let mut final_emissions = new_emissions(1);
+            final_emissions[0].value = 42;
+            assert!(contract.retire(asset_id, final_emissions.clone()).is_ok());
+
+            // Check emitted events
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let last = emitted_events.len() - 1;
+            assert_emissions_event(&emitted_events[last - 1], asset_id, &final_emissions[0]);
+            let decoded_event =
+                <Event as scale::Decode>::decode(&mut &emitted_events[last].data[..])
+                    .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::Retired(Retired { id }) if id == asset_id
+            ));
+
+            // Check if asset is not owned anymore
+            assert!(contract.owner_of(asset_id).is_none());
+            assert!(contract.list_assets(asset_owner).is_empty());
+            assert_eq!(contract.get_owner_emissions(asset_owner), 0);
+
+            // Check if history is kept
+            let asset = contract.get_asset(asset_id).unwrap();
+            assert_eq!(
+                asset.emissions.last(),
+                Some(&InfinityAsset::normalize_offset(final_emissions[0].clone()))
             );
+            assert_eq!(contract.query_emissions(asset_id), Some(Vec::from([asset])));
         }
 
         #[ink::test]
-        fn should_reject_not_owner_creating_child_in_blast() {
+        fn should_retired_asset_reject_actions() {
             let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            let emissions = new_emissions(1);
-            let metadata = default_metadata();
-
-            let parent: ParentDetails = Some(asset_id);
-
-            set_caller(accounts.alice);
+            set_caller(asset_owner);
+            assert!(contract.retire(asset_id, new_emissions(1)).is_ok());
 
             // Check if proper error is returned
-            // While trying to blast child asset as parent nonowner
+            // While trying to act on a retired asset
             assert_eq!(
-                contract.blast(asset_owner, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::NotOwner)
+                contract.transfer(accounts.eve, asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::AlreadyRetired)
+            );
+            assert_eq!(
+                contract.pause(asset_id),
+                Err(AssetCO2EmissionsError::AlreadyRetired)
+            );
+            assert_eq!(
+                contract.add_emissions(asset_id, default_emission_item()),
+                Err(AssetCO2EmissionsError::AlreadyRetired)
+            );
+            assert_eq!(
+                contract.retire(asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::AlreadyRetired)
             );
         }
 
         #[ink::test]
-        fn should_reject_not_paused_in_blast() {
+        fn should_retired_parent_keep_tree_walk_working() {
             let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            let child_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 1);
+            let parent_emissions = contract.get_asset_emissions(asset_id);
 
-            let emissions = new_emissions(1);
-            let metadata = default_metadata();
-
+            // Check if proper error is returned
+            // While trying to change CO2 Emissions inherited by the child
             set_caller(asset_owner);
+            assert_eq!(
+                contract.retire(asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::AlreadyPaused)
+            );
 
-            let parent: ParentDetails = Some(asset_id);
+            // Check if split parent gets retired without final emissions
+            assert!(contract.retire(asset_id, Vec::new()).is_ok());
+            assert_eq!(contract.get_asset_emissions(asset_id), parent_emissions);
 
-            // Check if proper error is returned
-            // While trying to blast a child asset for not paused parent
+            // Check if child's tree still contains the retired parent
+            let tree = contract.query_emissions(child_id).unwrap();
             assert_eq!(
-                contract.blast(asset_owner, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::NotPaused)
+                tree.iter().map(|asset| asset.asset_id).collect::<Vec<_>>(),
+                Vec::from([child_id, asset_id])
             );
+            assert_eq!(contract.lineage_size(child_id), Some(2));
+            assert!(contract.find_orphans(0, 10).is_empty());
         }
 
         #[ink::test]
-        fn should_blast_child() {
-            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
-
-            set_caller(asset_owner);
-
-            let metadata = default_metadata();
-            let emissions = new_emissions(1);
-            let parent: ParentDetails = Some(asset_id);
+        fn should_snapshots_work_properly() {
+            let (accounts, mut contract) = prepare_env();
 
-            // Pause parent asset
-            assert!(contract.pause(asset_id).is_ok());
+            // Check if proper error is returned
+            // While trying to take a snapshot as not contract owner
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.take_snapshot(),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
+            set_caller(accounts.alice);
 
-            // Blast child asset
+            let mut emissions = new_emissions(2);
+            emissions[0].value = 10;
+            emissions[1].value = 5;
             assert!(contract
-                .blast(asset_owner, metadata.clone(), emissions.clone(), parent)
+                .blast(accounts.alice, default_metadata(), emissions, None)
                 .is_ok());
+            assert_eq!(contract.total_recorded_emissions(), 15);
 
-            let expected_asset_id = 2;
+            let now = default_timestamp() * 1000;
+            test::set_block_timestamp::<DefaultEnvironment>(now + 1_000);
+            assert_eq!(contract.take_snapshot(), Ok(0));
 
-            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let mut item = default_emission_item();
+            item.value = 7;
+            assert!(contract.add_emissions(1, item).is_ok());
 
-            // Check events count
-            // 1 * Blasted + 1 * Emission + 1 * Paused + 1 * Blasted + 1 * Emission
-            assert_eq!(1 + 1 + 1 + 1 + 1, emitted_events.len());
+            test::set_block_timestamp::<DefaultEnvironment>(now + 2_000);
+            assert_eq!(contract.take_snapshot(), Ok(1));
 
-            // Check Blasted event
+            // Check if snapshots are frozen, with timestamps in seconds
+            let now = default_timestamp();
+            assert_eq!(contract.get_snapshot(0), Some((now + 1, 15)));
+            assert_eq!(contract.get_snapshot(1), Some((now + 2, 22)));
+            assert_eq!(contract.get_snapshot(2), None);
+        }
+
+        #[ink::test]
+        fn should_blast_batch_work_properly() {
+            let (accounts, mut contract) = prepare_env();
+
+            let items: Vec<_> = (0..50)
+                .map(|_| (default_metadata(), new_emissions(1), None))
+                .collect();
+            let ids = contract.blast_batch(accounts.bob, items).unwrap();
+
+            // Check if ids are contiguous and in the input order
+            assert_eq!(ids, (1..=50).collect::<Vec<AssetId>>());
+            assert_eq!(contract.list_assets(accounts.bob), ids);
+            assert_eq!(contract.next_id, 51);
+
+            // Check emitted events: Blasted & Emission per each Asset
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(emitted_events.len(), 100);
+            let emission = default_emission_item();
             assert_blasted_event(
-                &emitted_events[3],
-                expected_asset_id,
-                metadata,
-                asset_owner,
-                parent,
+                &emitted_events[98],
+                50,
+                default_metadata(),
+                accounts.bob,
+                None,
             );
+            assert_emissions_event(&emitted_events[99], 50, &emission);
+        }
 
-            // Check Emission event for already blasted child asset
-            assert_emissions_event(
-                &emitted_events[4],
-                expected_asset_id,
-                emissions[0].category,
-                emissions[0].data_source.clone(),
-                emissions[0].balanced,
-                emissions[0].date,
-                emissions[0].value,
+        #[ink::test]
+        fn should_blast_batch_reject_whole_batch() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            set_caller(asset_owner);
+            assert!(contract.pause(asset_id).is_ok());
+            assert!(contract.set_quantity(asset_id, 100).is_ok());
+            let next_id = contract.next_id;
+
+            // Check if proper error is returned
+            // While trying to blast a batch with a single invalid item
+            let mut items: Vec<_> = (0..3)
+                .map(|_| (default_metadata(), new_emissions(1), None))
+                .collect();
+            items[2].1 = Vec::new();
+            assert_eq!(
+                contract.blast_batch(accounts.bob, items),
+                Err(AssetCO2EmissionsError::EmissionsEmpty)
+            );
+
+            // While trying to split more than the parent quantity across the batch
+            let items: Vec<_> = (0..2)
+                .map(|_| (default_metadata(), new_emissions(1), Some((asset_id, 60))))
+                .collect();
+            assert_eq!(
+                contract.blast_batch(accounts.bob, items),
+                Err(AssetCO2EmissionsError::ParentQuantityExceeded)
+            );
+
+            // While trying to blast too many Assets at once
+            let items: Vec<_> = (0..=MAX_BLAST_BATCH)
+                .map(|_| (default_metadata(), new_emissions(1), None))
+                .collect();
+            assert_eq!(
+                contract.blast_batch(accounts.bob, items),
+                Err(AssetCO2EmissionsError::BatchOverflow)
+            );
+
+            // While trying to overflow the Asset ids
+            contract.next_id = AssetId::MAX - 1;
+            contract.id_high_water = AssetId::MAX - 1;
+            let items: Vec<_> = (0..2)
+                .map(|_| (default_metadata(), new_emissions(1), None))
+                .collect();
+            assert_eq!(
+                contract.blast_batch(accounts.bob, items),
+                Err(AssetCO2EmissionsError::AssetIdOverflow)
+            );
+            contract.next_id = next_id;
+            contract.id_high_water = next_id;
+
+            // Check if nothing was minted
+            assert!(contract.list_assets(accounts.bob).is_empty());
+            assert_eq!(contract.next_id, next_id);
+        }
+
+        #[ink::test]
+        fn should_storage_deposit_be_refunded_on_retire() {
+            let (accounts, mut contract) = prepare_env();
+
+            // Check if proper error is returned
+            // While trying to set the deposit as nonowner
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.set_storage_deposit(50),
+                Err(AssetCO2EmissionsError::NotContractOwner)
             );
+            set_caller(accounts.alice);
+            assert!(contract.set_storage_deposit(50).is_ok());
+            assert_eq!(contract.storage_deposit(), 50);
 
-            // Check child asset's parent
-            let parent_from_state = contract.get_parent_details(expected_asset_id);
-            assert!(parent_from_state.is_some());
-            assert_eq!(parent, parent_from_state.unwrap());
-        }
+            // Use a dedicated contract account, so its balance is independent of the owner's
+            let contract_account = accounts.frank;
+            test::set_callee::<DefaultEnvironment>(contract_account);
+            set_caller(accounts.bob);
 
-        #[ink::test]
-        fn should_not_add_emissions_to_nonexistent_asset() {
-            let mut contract = InfinityAsset::new();
+            // Check if proper error is returned
+            // While trying to blast without the deposit
+            test::set_value_transferred::<DefaultEnvironment>(49);
+            assert_eq!(
+                contract.blast(accounts.bob, default_metadata(), new_emissions(1), None),
+                Err(AssetCO2EmissionsError::InsufficientDeposit)
+            );
 
-            let item = default_emission_item();
+            test::set_value_transferred::<DefaultEnvironment>(50);
+            assert!(contract
+                .blast(accounts.bob, default_metadata(), new_emissions(1), None)
+                .is_ok());
+            test::set_account_balance::<DefaultEnvironment>(contract_account, 50);
+            test::set_value_transferred::<DefaultEnvironment>(0);
 
-            let asset_id = 1;
+            // Check if held deposit cannot be withdrawn
+            set_caller(accounts.alice);
+            assert_eq!(
+                contract.withdraw(1),
+                Err(AssetCO2EmissionsError::InsufficientBalance)
+            );
 
-            // Check if proper error is returned
-            // While trying to add CO2 Emissions to nonexistent asset
+            // Check if deposit is refunded to the retiring owner
+            set_caller(accounts.bob);
+            let bob_balance =
+                test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap();
+            assert!(contract.retire(1, new_emissions(1)).is_ok());
             assert_eq!(
-                contract.add_emissions(asset_id, item),
-                Err(AssetCO2EmissionsError::AssetNotFound)
+                test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap(),
+                bob_balance + 50
+            );
+            assert_eq!(
+                test::get_account_balance::<DefaultEnvironment>(contract_account).unwrap(),
+                0
             );
         }
 
         #[ink::test]
-        fn should_not_owner_not_be_able_to_add_emissions() {
-            let ((accounts, mut contract), (asset_id, _asset_owner)) = env_with_default_asset();
+        fn should_asset_counters_work_properly() {
+            let (accounts, mut contract) = prepare_env();
 
-            set_caller(accounts.bob);
+            // Check empty contract
+            assert_eq!(contract.total_assets(), 0);
+            assert_eq!(contract.active_assets(), 0);
 
-            // Check if proper error is returned
-            // While trying to add CO2 Emissions as asset's nonowner
-            assert_eq!(
-                contract.add_emissions(asset_id, default_emission_item()),
-                Err(AssetCO2EmissionsError::NotOwner)
-            );
+            for _ in 0..3 {
+                assert!(contract
+                    .blast(accounts.alice, default_metadata(), new_emissions(1), None)
+                    .is_ok());
+            }
+            assert!(contract
+                .blast_deterministic(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(1),
+                    None,
+                    7
+                )
+                .is_ok());
+            assert_eq!(contract.total_assets(), 4);
+            assert_eq!(contract.active_assets(), 4);
+
+            // Check if retirement decreases live Assets only
+            assert!(contract.retire(2, new_emissions(1)).is_ok());
+            assert_eq!(contract.total_assets(), 4);
+            assert_eq!(contract.active_assets(), 3);
         }
 
         #[ink::test]
-        fn should_reject_paused_in_add_emissions() {
+        fn should_latest_emission_work_properly() {
             let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            set_caller(asset_owner);
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.latest_emission(1000).is_none());
 
-            // Pause the asset first
-            assert!(contract.pause(asset_id).is_ok());
+            set_caller(asset_owner);
+            for value in 2..5 {
+                let mut item = default_emission_item();
+                item.value = value;
+                assert!(contract.add_emissions(asset_id, item).is_ok());
+            }
 
-            // Check if proper error is returned
-            // While trying to add CO2 Emissions in Paused state
+            // Check if the most recent item is returned
             assert_eq!(
-                contract.add_emissions(asset_id, default_emission_item()),
-                Err(AssetCO2EmissionsError::AlreadyPaused)
+                contract.latest_emission(asset_id).map(|item| item.value),
+                Some(4)
             );
         }
 
         #[ink::test]
-        fn should_reject_zero_emissions_item_in_add_emissions() {
-            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+        fn should_list_assets_paged_work_properly() {
+            let (accounts, mut contract) = prepare_env();
 
-            set_caller(asset_owner);
+            // Check if contract return proper value for account without assets
+            assert!(contract.list_assets_paged(accounts.bob, 0, 10).is_empty());
 
-            // Prepare Zero emissions item
-            let mut new_emission_item = default_emission_item();
-            new_emission_item.value = 0u128;
+            for _ in 0..5 {
+                assert!(contract
+                    .blast(accounts.bob, default_metadata(), new_emissions(1), None)
+                    .is_ok());
+            }
+
+            // Check consecutive pages
+            let first_page = contract.list_assets_paged(accounts.bob, 0, 2);
+            assert_eq!(first_page, Vec::from([1, 2]));
+            let second_page = contract.list_assets_paged(accounts.bob, first_page[1] + 1, 2);
+            assert_eq!(second_page, Vec::from([3, 4]));
+            let last_page = contract.list_assets_paged(accounts.bob, second_page[1] + 1, 2);
+            assert_eq!(last_page, Vec::from([5]));
+
+            // Check start past the end
+            assert!(contract.list_assets_paged(accounts.bob, 6, 2).is_empty());
+        }
+
+        #[ink::test]
+        fn should_list_assets_paged_limit_be_capped() {
+            let (accounts, mut contract) = prepare_env();
+
+            let count = u32::from(MAX_LIST_RETURN) + 1;
+            for _ in 0..count {
+                assert!(contract
+                    .blast(accounts.bob, default_metadata(), new_emissions(1), None)
+                    .is_ok());
+            }
 
-            // Check if proper error is returned
-            // While trying to add Zero emissions item in `add_emissions`
             assert_eq!(
-                contract.add_emissions(asset_id, new_emission_item),
-                Err(AssetCO2EmissionsError::ZeroEmissionsItem)
+                contract.list_assets_paged(accounts.bob, 0, count).len(),
+                usize::from(MAX_LIST_RETURN)
             );
         }
 
         #[ink::test]
-        fn should_owner_be_able_to_add_emissions() {
-            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+        fn should_two_phase_transfer_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
             set_caller(asset_owner);
-
-            let emission_item = default_emission_item();
-
-            // Add CO2 Emissions item
             assert!(contract
-                .add_emissions(asset_id, emission_item.clone())
+                .initiate_transfer(accounts.bob, asset_id, new_emissions(1))
                 .is_ok());
 
+            // Check if the Asset stays with the sender until acknowledged
+            assert_eq!(contract.owner_of(asset_id), Some(asset_owner));
             let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let decoded_event =
+                <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                    .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::TransferInitiated(TransferInitiated { id, from, to })
+                    if id == asset_id && from == asset_owner && to == accounts.bob
+            ));
 
-            // Check events count
-            // 1 * Blasted + 1 * Emissions + 1 * Emissions
-            assert_eq!(1 + 1 + 1, emitted_events.len());
-            assert_emissions_event(
-                &emitted_events[2],
-                asset_id,
-                emission_item.category,
-                emission_item.data_source.clone(),
-                emission_item.balanced,
-                emission_item.date,
-                emission_item.value,
+            // Check if proper error is returned
+            // While trying to acknowledge the transfer as not the recipient
+            set_caller(accounts.eve);
+            assert_eq!(
+                contract.acknowledge_transfer(asset_id),
+                Err(AssetCO2EmissionsError::TransferNotPending)
             );
+            assert_eq!(contract.owner_of(asset_id), Some(asset_owner));
 
-            let expected_emissions: Vec<CO2Emissions> =
-                Vec::from([default_emission_item(), emission_item]);
-            let emissions_from_state = contract.get_asset_emissions(asset_id);
+            set_caller(accounts.bob);
+            assert!(contract.acknowledge_transfer(asset_id).is_ok());
 
-            // Check if contract return proper CO2 Emissions data
-            assert!(emissions_from_state.is_some());
-            assert!(expected_emissions
-                .iter()
-                .eq(emissions_from_state.unwrap().iter()));
+            // Check if the ownership changed & transfer emissions got recorded
+            assert_eq!(contract.owner_of(asset_id), Some(accounts.bob));
+            assert_eq!(contract.get_asset_emissions(asset_id).unwrap().len(), 2);
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let decoded_event =
+                <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                    .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::TransferAcknowledged(TransferAcknowledged { id, from, to })
+                    if id == asset_id && from == asset_owner && to == accounts.bob
+            ));
+
+            // Check if the transfer cannot be acknowledged twice
+            assert_eq!(
+                contract.acknowledge_transfer(asset_id),
+                Err(AssetCO2EmissionsError::TransferNotPending)
+            );
         }
 
         #[ink::test]
-        fn should_not_transfer_nonexistent_asset() {
-            let (accounts, mut contract) = prepare_env();
-
-            let item = default_emission_item();
-
-            let asset_id = 1;
+        fn should_direct_transfer_cancel_pending_transfer() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            set_caller(accounts.alice);
+            set_caller(asset_owner);
+            assert!(contract
+                .initiate_transfer(accounts.bob, asset_id, new_emissions(1))
+                .is_ok());
+            assert!(contract
+                .transfer(accounts.charlie, asset_id, new_emissions(1))
+                .is_ok());
 
             // Check if proper error is returned
-            // While trying to transfer nonexistent asset
+            // While trying to acknowledge a transfer cancelled by a direct transfer
+            set_caller(accounts.bob);
             assert_eq!(
-                contract.transfer(accounts.bob, asset_id, Vec::from([item])),
-                Err(AssetCO2EmissionsError::AssetNotFound)
+                contract.acknowledge_transfer(asset_id),
+                Err(AssetCO2EmissionsError::TransferNotPending)
             );
+            assert_eq!(contract.owner_of(asset_id), Some(accounts.charlie));
         }
 
         #[ink::test]
-        fn should_not_owner_not_be_able_to_transfer() {
-            let ((accounts, mut contract), (asset_id, _asset_owner)) = env_with_default_asset();
+        fn should_total_tree_emissions_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            set_caller(accounts.bob);
+            // Check if contract return proper value for nonexistent asset
+            assert_eq!(contract.total_tree_emissions(1000), Ok(None));
+
+            // Check long chain of maximum depth
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, asset_id, MAX_TREE_DEPTH);
+            assert_eq!(
+                contract.total_tree_emissions(leaf_id),
+                Ok(Some(u128::from(MAX_TREE_DEPTH) + 1))
+            );
 
             // Check if proper error is returned
-            // While trying to transfer asset as nonowner
+            // While the total exceeds maximum value
+            assert!(contract
+                .blast(accounts.eve, default_metadata(), new_emissions(1), None)
+                .is_ok());
+            let root_id = contract.next_id - 1;
+            let child_id = blast_child_chain(&mut contract, &accounts.eve, root_id, 1);
+            let mut emissions = new_emissions(1);
+            emissions[0].value = u128::MAX;
+            contract.co2_emissions.insert(root_id, &emissions);
             assert_eq!(
-                contract.transfer(accounts.bob, asset_id, new_emissions(1)),
-                Err(AssetCO2EmissionsError::NotOwner)
+                contract.total_tree_emissions(child_id),
+                Err(AssetCO2EmissionsError::EmissionsTotalOverflow)
             );
         }
 
         #[ink::test]
-        fn should_reject_transfer_in_paused() {
-            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+        fn should_category_count_in_lineage_work_properly() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.category_count_in_lineage(1000).is_none());
+
+            // Check lineage using a single category
+            let child_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 1);
+            assert_eq!(contract.category_count_in_lineage(child_id), Some(1));
 
+            // Check lineage using all categories
             set_caller(asset_owner);
+            let mut item = default_emission_item();
+            item.category = EmissionsCategory::Transport;
+            assert!(contract.add_emissions(child_id, item.clone()).is_ok());
+            item.category = EmissionsCategory::Process;
+            assert!(contract.add_emissions(child_id, item).is_ok());
+            assert_eq!(contract.category_count_in_lineage(child_id), Some(3));
+            assert_eq!(contract.category_count_in_lineage(asset_id), Some(1));
+        }
 
-            // Pause an asset
-            assert!(contract.pause(asset_id).is_ok());
+        #[ink::test]
+        fn should_emissions_per_generation_work_properly() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            // Check if proper error is returned
-            // While trying to transfer asset in Paused state
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.emissions_per_generation(1000).is_none());
+
+            // Build 3-level chain with distinct contributions
+            let middle_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 1);
+            let mut item = default_emission_item();
+            item.value = 4;
+            assert!(contract.add_emissions(middle_id, item.clone()).is_ok());
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, middle_id, 1);
+            item.value = 10;
+            assert!(contract.add_emissions(leaf_id, item).is_ok());
+
+            // Check each generation's contribution, root first
             assert_eq!(
-                contract.transfer(accounts.bob, asset_id, new_emissions(2)),
-                Err(AssetCO2EmissionsError::AlreadyPaused)
+                contract.emissions_per_generation(leaf_id),
+                Some(Vec::from([1, 5, 11]))
+            );
+            assert_eq!(
+                contract.emissions_per_generation(asset_id),
+                Some(Vec::from([1]))
             );
         }
 
         #[ink::test]
-        fn should_reject_empty_emissions_in_transfer() {
-            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+        fn should_get_emissions_in_range_work_properly() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.get_emissions_in_range(1000, 0, u64::MAX).is_none());
 
             set_caller(asset_owner);
+            for date in [1_000, 2_000, 3_000] {
+                let mut item = default_emission_item();
+                item.date = date;
+                assert!(contract.add_emissions(asset_id, item).is_ok());
+            }
+            let dates = |emissions: Option<Vec<CO2Emissions>>| -> Vec<u64> {
+                emissions.unwrap().iter().map(|item| item.date).collect()
+            };
 
-            // Check if proper error is returned
-            // While trying to transfer asset with empty vector of CO2 Emissions items
+            // Check boundaries are inclusive
             assert_eq!(
-                contract.transfer(accounts.bob, asset_id, Vec::new()),
-                Err(AssetCO2EmissionsError::EmissionsEmpty)
+                dates(contract.get_emissions_in_range(asset_id, 1_000, 2_000)),
+                Vec::from([1_000, 2_000])
+            );
+            assert_eq!(
+                dates(contract.get_emissions_in_range(asset_id, 2_000, 2_000)),
+                Vec::from([2_000])
+            );
+
+            // Check empty range
+            assert_eq!(
+                contract.get_emissions_in_range(asset_id, 1_001, 1_999),
+                Some(Vec::new())
             );
         }
 
         #[ink::test]
-        fn should_reject_too_many_emissions_on_blast() {
-            let (accounts, mut contract) = prepare_env();
-
-            let metadata = default_metadata();
-            let parent = None;
+        fn should_reject_future_dated_emissions() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            let now = default_timestamp();
 
-            let emissions: Vec<CO2Emissions> = new_emissions(MAX_EMISSIONS_PER_ASSET + 1);
+            let mut future_item = default_emission_item();
+            future_item.date = now + FUTURE_DATE_TOLERANCE + 1;
 
-            let expected_asset_id = 1;
-            let owner = accounts.alice;
+            // Check if proper error is returned
+            // While trying to blast with a future-dated item
+            assert_eq!(
+                contract.blast(
+                    accounts.alice,
+                    default_metadata(),
+                    Vec::from([future_item.clone()]),
+                    None
+                ),
+                Err(AssetCO2EmissionsError::FutureEmissionsDate)
+            );
 
-            set_caller(owner);
+            // While trying to add a future-dated item
+            set_caller(asset_owner);
+            assert_eq!(
+                contract.add_emissions(asset_id, future_item.clone()),
+                Err(AssetCO2EmissionsError::FutureEmissionsDate)
+            );
 
-            // Check if proper error is returned
-            // While trying to blast asset with too many emissions items
+            // While trying to transfer with a future-dated item
             assert_eq!(
-                contract.blast(owner, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::EmissionsOverflow)
+                contract.transfer(accounts.bob, asset_id, Vec::from([future_item])),
+                Err(AssetCO2EmissionsError::FutureEmissionsDate)
             );
 
-            // Check if asset is not blasted
-            assert!(contract.get_asset(expected_asset_id).is_none());
+            // Check if items dated now or within the tolerance pass
+            let mut item = default_emission_item();
+            assert!(contract.add_emissions(asset_id, item.clone()).is_ok());
+            item.date = now + FUTURE_DATE_TOLERANCE;
+            assert!(contract.add_emissions(asset_id, item).is_ok());
         }
 
         #[ink::test]
-        fn should_reject_too_many_emissions_on_add() {
+        fn should_two_step_ownership_transfer_work_properly() {
             let (accounts, mut contract) = prepare_env();
 
-            let metadata = default_metadata();
-            let parent = None;
-
-            let emissions: Vec<CO2Emissions> = new_emissions(MAX_EMISSIONS_PER_ASSET);
-
-            let asset_id = 1;
-            let owner = accounts.alice;
-
-            set_caller(owner);
+            // Check if proper error is returned
+            // While trying to propose a new contract owner as nonowner
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.propose_contract_owner(accounts.bob),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
 
-            // Blast asset with max emissions items count
-            assert!(contract.blast(owner, metadata, emissions, parent).is_ok());
+            set_caller(accounts.alice);
+            assert!(contract.propose_contract_owner(accounts.bob).is_ok());
 
-            let item = default_emission_item();
+            // Check if ownership stays until accepted
+            assert_eq!(contract.contract_owner, accounts.alice);
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let decoded_event =
+                <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                    .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::OwnershipProposed(OwnershipProposed { new_owner }) if new_owner == accounts.bob
+            ));
 
             // Check if proper error is returned
-            // While trying to add emission item causing overflow
+            // While trying to accept the ownership as not the proposed owner
+            set_caller(accounts.eve);
             assert_eq!(
-                contract.add_emissions(asset_id, item.clone()),
-                Err(AssetCO2EmissionsError::EmissionsOverflow)
+                contract.accept_contract_ownership(),
+                Err(AssetCO2EmissionsError::NotPendingOwner)
             );
+            assert_eq!(contract.contract_owner, accounts.alice);
 
-            // Check if proper error is returned
-            // While trying to transfer with emission item causing overflow
+            set_caller(accounts.bob);
+            assert!(contract.accept_contract_ownership().is_ok());
+
+            // Check if new contract owner is properly set
+            assert_eq!(contract.contract_owner, accounts.bob);
+            assert_eq!(contract.pending_owner, None);
+            let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            let decoded_event =
+                <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                    .expect("Encountered invalid contract event data buffer");
+            assert!(matches!(
+                decoded_event,
+                Event::OwnershipTransferred(OwnershipTransferred { previous_owner, new_owner })
+                    if previous_owner == accounts.alice && new_owner == accounts.bob
+            ));
+
+            // Check if the proposal cannot be accepted twice
             assert_eq!(
-                contract.transfer(accounts.bob, asset_id, Vec::from([item])),
-                Err(AssetCO2EmissionsError::EmissionsOverflow)
+                contract.accept_contract_ownership(),
+                Err(AssetCO2EmissionsError::NotPendingOwner)
             );
         }
 
         #[ink::test]
-        fn should_reject_too_many_chars_in_data_source() {
-            let (accounts, mut contract) = prepare_env();
-
-            let metadata = default_metadata();
+        fn should_methodology_registry_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            let parent = None;
+            // Check if proper error is returned
+            // While trying to register a methodology as nonowner
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.set_methodology(14064, true),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
 
-            let emissions_data_source = vec![1u8; MAX_DATA_SOURCE_LENGTH as usize + 1];
+            // While trying to record against an unregistered methodology
+            set_caller(asset_owner);
             let mut item = default_emission_item();
-            item.data_source = emissions_data_source;
+            item.methodology = 14064;
+            assert_eq!(
+                contract.add_emissions(asset_id, item.clone()),
+                Err(AssetCO2EmissionsError::UnknownMethodology)
+            );
 
-            let emissions: Vec<CO2Emissions> = Vec::from([item]);
+            set_caller(accounts.alice);
+            assert!(contract.set_methodology(14064, true).is_ok());
+            assert!(contract.is_methodology_registered(14064));
+            assert!(contract.is_methodology_registered(0));
 
-            let owner = accounts.alice;
+            set_caller(asset_owner);
+            item.value = 5;
+            assert!(contract.add_emissions(asset_id, item).is_ok());
+
+            // Check filtering by methodology
+            let values = |emissions: Option<Vec<CO2Emissions>>| -> Vec<u128> {
+                emissions.unwrap().iter().map(|item| item.value).collect()
+            };
+            assert_eq!(
+                values(contract.emissions_by_methodology(asset_id, 14064)),
+                Vec::from([5])
+            );
+            assert_eq!(
+                values(contract.emissions_by_methodology(asset_id, 0)),
+                Vec::from([1])
+            );
+            assert!(contract.emissions_by_methodology(1000, 0).is_none());
 
-            set_caller(owner);
+            // Check if unregistered methodology is rejected again
+            set_caller(accounts.alice);
+            assert!(contract.set_methodology(14064, false).is_ok());
+            assert!(!contract.is_methodology_registered(14064));
+        }
 
-            let expected_asset_id = 1;
-            let owner = accounts.alice;
+        #[ink::test]
+        fn should_get_children_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            set_caller(owner);
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.get_children(1000).is_none());
 
-            // Check if proper error is returned
-            // While trying to blast asset with emissions item `data_source` overflow
-            assert_eq!(
-                contract.blast(owner, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::DataSourceOverflow)
-            );
+            // Check leaf asset
+            assert_eq!(contract.get_children(asset_id), Some(Vec::new()));
 
-            // Check if asset is not blasted
-            assert!(contract.get_asset(expected_asset_id).is_none());
+            set_caller(asset_owner);
+            assert!(contract.pause(asset_id).is_ok());
+            let items: Vec<_> = (0..3)
+                .map(|_| (default_metadata(), new_emissions(1), Some((asset_id, 1))))
+                .collect();
+            let ids = contract.blast_batch(accounts.bob, items).unwrap();
+
+            // Check if every child is listed in blasting order
+            assert_eq!(contract.get_children(asset_id), Some(ids.clone()));
+            assert_eq!(contract.get_children(ids[0]), Some(Vec::new()));
         }
 
         #[ink::test]
-        fn should_reject_zero_emissions_item_in_transfer() {
+        fn should_reject_children_overflow() {
             let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
             set_caller(asset_owner);
+            assert!(contract.pause(asset_id).is_ok());
+            for _ in 0..MAX_CHILDREN_PER_ASSET {
+                assert!(contract
+                    .blast(
+                        accounts.bob,
+                        default_metadata(),
+                        new_emissions(1),
+                        Some((asset_id, 1))
+                    )
+                    .is_ok());
+            }
 
-            let mut emissions = new_emissions(4);
-            emissions[2].value = 0;
+            // Check if parent with maximum children is reported as unusable
+            assert!(!contract.can_be_parent(asset_owner, asset_id));
 
             // Check if proper error is returned
-            // While trying to transfer with Zero emission item
+            // While trying to split the parent once more
             assert_eq!(
-                contract.transfer(accounts.bob, asset_id, emissions),
-                Err(AssetCO2EmissionsError::ZeroEmissionsItem)
+                contract.blast(
+                    accounts.bob,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((asset_id, 1))
+                ),
+                Err(AssetCO2EmissionsError::ChildrenOverflow)
+            );
+            assert_eq!(
+                contract
+                    .get_children(asset_id)
+                    .map(|children| children.len()),
+                Some(MAX_CHILDREN_PER_ASSET.into())
             );
         }
 
         #[ink::test]
-        fn should_reject_too_many_chars_in_metadata() {
-            let (accounts, mut contract) = prepare_env();
+        fn should_ancestor_ids_work_properly() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            let metadata: Metadata = vec![0u8; MAX_METADATA_LENGTH as usize + 1];
+            // Check if contract return proper value for nonexistent asset
+            assert!(contract.ancestor_ids(1000).is_none());
 
-            let parent = None;
+            // Check root asset
+            assert_eq!(contract.ancestor_ids(asset_id), Some(Vec::from([asset_id])));
 
-            let emissions = new_emissions(1);
+            // Check known chain, from the queried asset up to the root
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 2);
+            assert_eq!(
+                contract.ancestor_ids(leaf_id),
+                Some(Vec::from([leaf_id, leaf_id - 1, asset_id]))
+            );
+        }
 
-            let expected_asset_id = 1;
-            let owner = accounts.alice;
+        #[ink::test]
+        fn should_cyclic_tree_walk_terminate() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            let child_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 1);
+            assert!(contract.validate_tree_acyclic(child_id));
 
-            set_caller(owner);
+            // Simulate a faulty migration linking the root back to its child
+            contract.parent.insert(asset_id, &Some((child_id, 1)));
 
-            // Check if proper error is returned
-            // While trying to blast asset with `metadata` overflow
+            // Check if the cycle is detected and the walk terminates with the partial path
+            assert!(!contract.validate_tree_acyclic(child_id));
+            assert!(!contract.validate_tree_acyclic(asset_id));
             assert_eq!(
-                contract.blast(owner, metadata, emissions, parent),
-                Err(AssetCO2EmissionsError::MetadataOverflow)
+                contract
+                    .query_emissions(child_id)
+                    .map(|tree| tree.iter().map(|asset| asset.asset_id).collect::<Vec<_>>()),
+                Some(Vec::from([child_id, asset_id]))
             );
-
-            // Check if asset is not blasted
-            assert!(contract.get_asset(expected_asset_id).is_none());
         }
 
         #[ink::test]
-        fn should_owner_be_able_to_transfer() {
+        fn should_auto_pause_on_cap_work_properly() {
             let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            set_caller(asset_owner);
-
-            let _item = default_emission_item();
-
-            let new_owner = accounts.bob;
+            // Check if proper error is returned
+            // While trying to enable auto pause as nonowner
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.set_auto_pause_on_cap(true),
+                Err(AssetCO2EmissionsError::NotContractOwner)
+            );
 
-            let emissions = new_emissions(1);
+            // Check disabled mode: reaching the cap does not pause
+            set_caller(asset_owner);
+            for _ in 1..MAX_EMISSIONS_PER_ASSET {
+                assert!(contract
+                    .add_emissions(asset_id, default_emission_item())
+                    .is_ok());
+            }
+            assert_eq!(contract.has_paused(asset_id), Some(false));
 
-            // Transfer asset
+            // Check enabled mode: the pause fires exactly at the cap
+            set_caller(accounts.alice);
+            assert!(contract.set_auto_pause_on_cap(true).is_ok());
             assert!(contract
-                .transfer(new_owner, asset_id, emissions.clone())
+                .blast(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(MAX_EMISSIONS_PER_ASSET - 2),
+                    None
+                )
                 .is_ok());
-
+            let other_id = contract.next_id - 1;
+            assert!(contract
+                .add_emissions(other_id, default_emission_item())
+                .is_ok());
+            assert_eq!(contract.has_paused(other_id), Some(false));
+            assert!(contract
+                .add_emissions(other_id, default_emission_item())
+                .is_ok());
+            assert_eq!(contract.has_paused(other_id), Some(true));
             let emitted_events = test::recorded_events().collect::<Vec<_>>();
+            assert_paused_event(emitted_events.last().unwrap(), other_id);
+        }
 
-            // Check emitted evetns count
-            // 1 * Blasted + 1 * Emission + 1 * Transfer + 1 * Emission
-            assert_eq!(1 + 1 + 1 + 1, emitted_events.len());
+        #[ink::test]
+        fn should_reject_tree_depth_exceeded() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            // Check Transfer event
-            assert_transfer_event(&emitted_events[2], asset_id, asset_owner, new_owner);
+            // Check if blasting at the depth limit succeeds
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, asset_id, MAX_TREE_DEPTH);
+            assert_eq!(contract.depth.get(leaf_id), Some(MAX_TREE_DEPTH));
 
-            // Check Emission event
-            assert_emissions_event(
-                &emitted_events[3],
-                asset_id,
-                emissions[0].category,
-                emissions[0].data_source.clone(),
-                emissions[0].balanced,
-                emissions[0].date,
-                emissions[0].value,
+            // Check if proper error is returned
+            // While trying to blast one level past the limit
+            set_caller(asset_owner);
+            assert!(contract.pause(leaf_id).is_ok());
+            assert_eq!(
+                contract.blast(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(1),
+                    Some((leaf_id, 1))
+                ),
+                Err(AssetCO2EmissionsError::TreeDepthExceeded)
             );
-
-            let expected_emissions: Vec<CO2Emissions> =
-                Vec::from([default_emission_item(), emissions[0].clone()]);
-            let emissions_from_state = contract.get_asset_emissions(asset_id);
-
-            // Check asset's CO2 Emissions items
-            assert!(emissions_from_state.is_some());
-            assert!(expected_emissions
-                .iter()
-                .eq(emissions_from_state.unwrap().iter()));
-
-            // Check asset's owner
-            let owner_from_state = contract.owner_of(asset_id);
-            assert!(owner_from_state.is_some());
-            assert_eq!(new_owner, owner_from_state.unwrap());
         }
 
         #[ink::test]
-        fn should_nonexistent_asset_query_emissions_work_properly() {
-            let contract = InfinityAsset::new();
-            assert!(contract.query_emissions(69).is_none());
+        fn should_depth_fall_back_to_parent_links() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            let leaf_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 2);
+
+            // Simulate Assets blasted before depths were stored
+            contract.depth.remove(leaf_id);
+            assert_eq!(contract.depth_of(leaf_id), 2);
+            assert_eq!(contract.depth_of(asset_id), 0);
         }
 
         #[ink::test]
-        fn should_query_emissions_for_single_asset_work_properly() {
-            let ((_accounts, contract), (asset_id, asset_owner)) = env_with_default_asset();
-
-            set_caller(asset_owner);
+        fn should_portfolio_stats_work_properly() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
-            let emissions = Vec::from([default_emission_item()]);
-            let metadata: Metadata = default_metadata();
-            let parent = None;
+            // Check account without assets
+            assert_eq!(
+                contract.portfolio_stats(accounts.eve),
+                EmissionStats::default()
+            );
 
-            let expected_value: Vec<AssetDetails> = Vec::from([AssetDetails {
-                asset_id,
-                metadata,
-                emissions,
-                parent,
-            }]);
+            // Second asset: unbalanced 10 and partially offset 20
+            let mut emissions = new_emissions(2);
+            emissions[0].value = 10;
+            emissions[0].balanced = false;
+            emissions[1].value = 20;
+            emissions[1].offset_value = 5;
+            assert!(contract
+                .blast(asset_owner, default_metadata(), emissions, None)
+                .is_ok());
+            let other_id = contract.next_id - 1;
 
-            let details_from_state = contract.query_emissions(asset_id);
+            // Child asset held by another account does not count, nor do its ancestors for that account
+            let child_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 1);
+            set_caller(asset_owner);
+            assert!(contract
+                .transfer(accounts.bob, child_id, new_emissions(1))
+                .is_ok());
 
-            // Check single asset tree
-            assert!(details_from_state.is_some());
-            assert_eq!(expected_value, details_from_state.unwrap());
+            assert_eq!(
+                contract.portfolio_stats(asset_owner),
+                EmissionStats {
+                    count: 3,
+                    total: 31,
+                    balanced: 6,
+                }
+            );
+            assert_eq!(
+                contract.portfolio_stats(accounts.bob),
+                EmissionStats {
+                    count: 2,
+                    total: 2,
+                    balanced: 2,
+                }
+            );
+            assert!(contract.list_assets(asset_owner).contains(&other_id));
         }
 
         #[ink::test]
-        fn should_query_emissions_for_longer_path_work_properly() {
-            let ((_accounts, mut contract), (mut asset_id, asset_owner)) = env_with_default_asset();
-
-            let metadata = default_metadata();
-
-            let emissions: Vec<CO2Emissions> = Vec::from([default_emission_item()]);
-
-            let mut expected_tree_path: Vec<AssetDetails> = Vec::from([AssetDetails {
-                asset_id,
-                metadata: metadata.clone(),
-                emissions,
-                parent: None,
-            }]);
-
-            let timestamp = 1_000_000_000u64;
+        fn should_acknowledged_transfer_clear_pending_offer() {
+            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
 
             set_caller(asset_owner);
-            // create long token tree path
-            for i in 1..1_000 {
-                let parent: ParentDetails = Some(asset_id);
-
-                let mut emissions = new_emissions(1);
-                emissions[0].value = i;
-                emissions[0].date = timestamp + i as u64;
-
-                // Pause an asset
-                assert!(contract.pause(asset_id).is_ok());
-
-                // Blast child asset
-                assert!(contract
-                    .blast(asset_owner, metadata.clone(), emissions.clone(), parent)
-                    .is_ok());
-
-                asset_id += 1;
-                expected_tree_path.insert(
-                    0,
-                    AssetDetails {
-                        asset_id,
-                        metadata: metadata.clone(),
-                        emissions,
-                        parent,
-                    },
-                );
-            }
 
-            let details_from_state = contract.query_emissions(asset_id);
+            // Offer the asset and then hand it over to another account in two phases
+            assert!(contract.offer(asset_id, accounts.bob).is_ok());
+            assert!(contract
+                .initiate_transfer(accounts.eve, asset_id, new_emissions(1))
+                .is_ok());
+            set_caller(accounts.eve);
+            assert!(contract.acknowledge_transfer(asset_id).is_ok());
 
-            // Check extended asset tree
-            assert!(details_from_state.is_some());
-            assert_eq!(expected_tree_path, details_from_state.unwrap());
-        }
+            // Check if offer is cleared
+            assert!(!contract.offers.contains(asset_id));
 
-        #[ink::test]
-        fn should_list_asset_for_empty_account_work_properly() {
-            let (accounts, contract) = prepare_env();
+            set_caller(accounts.bob);
 
-            // Check if contract return proper value for `query_emissions`
-            assert_eq!(contract.list_assets(accounts.alice), Vec::<AssetId>::new());
+            // Check if proper error is returned
+            // While trying to claim an asset whose offer got cleared
+            assert_eq!(
+                contract.claim(asset_id, new_emissions(1)),
+                Err(AssetCO2EmissionsError::NotOffered)
+            );
+            assert_eq!(contract.owner_of(asset_id), Some(accounts.eve));
         }
 
         #[ink::test]
-        fn should_list_asset_for_single_asset_work_properly() {
-            let ((_accounts, contract), (asset_id, asset_owner)) = env_with_default_asset();
+        fn should_reject_deterministic_blast_replay_after_retire() {
+            let (accounts, mut contract) = prepare_env();
 
-            set_caller(asset_owner);
+            let asset_id = contract
+                .blast_deterministic(
+                    accounts.alice,
+                    default_metadata(),
+                    new_emissions(1),
+                    None,
+                    7,
+                )
+                .unwrap();
+            assert!(contract.retire(asset_id, new_emissions(1)).is_ok());
+            let history = contract.get_asset(asset_id);
 
-            // Check if contract return proper value for `list_assets`
+            // Check if proper error is returned
+            // While trying to replay the deterministic blast of a retired asset
             assert_eq!(
-                Vec::<AssetId>::from([asset_id]),
-                contract.list_assets(asset_owner)
+                contract.blast_deterministic(
+                    accounts.alice,
+                    Vec::from([9u8]),
+                    new_emissions(1),
+                    None,
+                    7
+                ),
+                Err(AssetCO2EmissionsError::AssetAlreadyExists)
             );
+
+            // Check if retired asset stays untouched
+            assert_eq!(contract.get_asset(asset_id), history);
+            assert!(contract.list_assets(accounts.alice).is_empty());
+            assert!(contract.owner_of(asset_id).is_none());
         }
 
         #[ink::test]
-        fn should_list_assets_for_many_assets_work_properly() {
-            let ((_accounts, mut contract), (mut asset_id, asset_owner)) = env_with_default_asset();
-
-            let metadata = default_metadata();
-
-            let timestamp = 1_000_000_000u64;
+        fn should_retired_asset_be_handled_consistently() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            let child_id = blast_child_chain(&mut contract, &asset_owner, asset_id, 1);
 
             set_caller(asset_owner);
+            assert!(contract.retire(child_id, new_emissions(1)).is_ok());
 
-            // create long token tree path
-            for i in 1..1_000 {
-                let parent: ParentDetails = Some(asset_id);
-                let mut emissions = new_emissions(1);
-                emissions[0].value = i;
-                emissions[0].date = timestamp + i as u64;
-
-                // Pause an asset
-                assert!(contract.pause(asset_id).is_ok());
-                // Blast a child
-                assert!(contract
-                    .blast(asset_owner, metadata.clone(), emissions.clone(), parent)
-                    .is_ok());
-
-                asset_id += 1;
-            }
-
-            let mut assets_from_state = contract.list_assets(asset_owner);
-            assets_from_state.sort();
+            // Check if proper error is returned
+            // While trying to act on a retired asset through any message
+            assert_eq!(
+                contract.pause_batch(Vec::from([child_id])),
+                Err(AssetCO2EmissionsError::AlreadyRetired)
+            );
+            assert_eq!(
+                contract.offer(child_id, asset_owner),
+                Err(AssetCO2EmissionsError::AlreadyRetired)
+            );
 
-            // Check if contract return proper value
-            assert_eq!((1..1_001).collect::<Vec<AssetId>>(), assets_from_state);
+            // Check if read-only tree queries keep working for the retired asset
+            assert_eq!(contract.lineage_size(child_id), Some(2));
+            assert_eq!(contract.total_with_uncertainty(child_id), Some((3, 0)));
+            assert_eq!(
+                contract.ancestor_ids(child_id),
+                Some(Vec::from([child_id, asset_id]))
+            );
         }
 
         #[ink::test]
-        fn should_list_asset_after_transfer_work_properly() {
-            let ((accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
-
-            let new_owner = accounts.bob;
+        fn should_reject_total_balanced_emissions_overflow() {
+            let ((_accounts, mut contract), (asset_id, asset_owner)) = env_with_default_asset();
+            contract.total_balanced_emissions = u128::MAX - 1;
 
+            // Check if proper error is returned
+            // While trying to record balanced emissions overflowing the total
             set_caller(asset_owner);
+            let mut item = default_emission_item();
+            item.value = 2;
+            assert_eq!(
+                contract.add_emissions(asset_id, item),
+                Err(AssetCO2EmissionsError::EmissionsTotalOverflow)
+            );
 
-            // Check owned assets before `transfer`
-            assert_eq!(Vec::from([asset_id]), contract.list_assets(asset_owner));
-            assert_eq!(Vec::<AssetId>::new(), contract.list_assets(new_owner));
+            // Check if nothing was recorded
+            assert_eq!(contract.total_balanced_emissions(), u128::MAX - 1);
+            assert_eq!(contract.get_asset_emissions(asset_id).unwrap().len(), 1);
 
-            // Transfer asset
+            // Check if the total can still reach its maximum
             assert!(contract
-                .transfer(new_owner, asset_id, new_emissions(1))
+                .add_emissions(asset_id, default_emission_item())
                 .is_ok());
-
-            // Check owned assets after `transfer`
-            assert_eq!(Vec::<AssetId>::new(), contract.list_assets(asset_owner));
-            assert_eq!(Vec::from([asset_id]), contract.list_assets(new_owner));
+            assert_eq!(contract.total_balanced_emissions(), u128::MAX);
         }
     }
 }